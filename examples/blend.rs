@@ -0,0 +1,64 @@
+use bevy::prelude::*;
+use jabu_debug_draw::prelude::*;
+
+fn main() {
+    App::new()
+        .insert_resource(ClearColor(Color::rgb(0.1, 0.1, 0.1)))
+        .add_plugins(DefaultPlugins)
+        .add_plugin(DebugDrawPlugin)
+        .add_startup_system(setup)
+        .add_system(draw)
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2dBundle::default());
+}
+
+fn draw(mut debug_draw: ResMut<DebugDraw>, time: Res<Time>) {
+    for index in 0..8 {
+        debug_draw.draw(DebugRectangle {
+            position: Vec2::new(-350. + index as f32 * 100., 0.),
+            size: Vec2::new(50., 500.),
+            color: Color::WHITE,
+            ..Default::default()
+        });
+    }
+
+    debug_draw.set_blend_mode(DebugDrawBlendMode::Additive);
+    for (offset, color) in [
+        (Vec2::new(-250., 120.), Color::RED),
+        (Vec2::new(-190., 160.), Color::GREEN),
+        (Vec2::new(-190., 80.), Color::BLUE),
+    ] {
+        debug_draw.draw(DebugCircle {
+            position: offset,
+            radius: 150.,
+            color,
+            depth: 1.,
+            ..Default::default()
+        });
+    }
+
+    debug_draw.set_blend_mode(DebugDrawBlendMode::Multiply);
+    debug_draw.draw(DebugRectangle {
+        position: Vec2::new(200., 120.),
+        size: Vec2::splat(150.),
+        color: Color::ORANGE,
+        depth: 1.,
+        ..Default::default()
+    });
+    debug_draw.set_blend_mode(DebugDrawBlendMode::Alpha);
+
+    let x = time.elapsed_seconds().sin() * 300.;
+    debug_draw.draw_blended(
+        DebugDrawBlendMode::Invert,
+        DebugCircle {
+            position: Vec2::new(x, -150.),
+            radius: 80.,
+            color: Color::WHITE,
+            depth: 2.,
+            ..Default::default()
+        },
+    );
+}
@@ -0,0 +1,66 @@
+use bevy::prelude::*;
+use jabu_debug_draw::prelude::*;
+
+struct Physics;
+
+fn main() {
+    App::new()
+        .insert_resource(ClearColor(Color::rgb(0.1, 0.1, 0.1)))
+        .add_plugins(DefaultPlugins)
+        .add_plugin(DebugDrawPlugin)
+        .add_startup_system(setup)
+        .add_system(toggle_categories)
+        .add_system(draw)
+        .run();
+}
+
+fn setup(mut commands: Commands, mut debug_draw: ResMut<DebugDraw>) {
+    commands.spawn(Camera2dBundle::default());
+    let ai = debug_draw.category_settings_mut("ai");
+    ai.tint = Some(Color::GREEN);
+    ai.opacity = 0.5;
+}
+
+fn toggle_categories(keys: Res<Input<KeyCode>>, mut debug_draw: ResMut<DebugDraw>) {
+    if keys.just_pressed(KeyCode::Key1) {
+        debug_draw.toggle_category("ai");
+    }
+    if keys.just_pressed(KeyCode::Key2) {
+        debug_draw.toggle_category(DebugDrawCategory::of::<Physics>());
+    }
+    if keys.just_pressed(KeyCode::S) {
+        if debug_draw.solo().is_some() {
+            debug_draw.clear_solo();
+        } else {
+            debug_draw.solo_category("ai");
+        }
+    }
+}
+
+fn draw(mut debug_draw: ResMut<DebugDraw>) {
+    debug_draw.draw_in(
+        "ai",
+        DebugCircle {
+            position: Vec2::new(-150., 0.),
+            radius: 100.,
+            color: Color::WHITE,
+            ..Default::default()
+        },
+    );
+
+    debug_draw.set_category(DebugDrawCategory::of::<Physics>());
+    debug_draw.draw(DebugRectangle {
+        position: Vec2::new(150., 0.),
+        size: Vec2::new(100., 100.),
+        color: Color::ORANGE,
+        ..Default::default()
+    });
+    debug_draw.clear_category();
+
+    debug_draw.draw(DebugText {
+        text: "1: ai  2: physics  S: solo ai".to_owned(),
+        position: Vec2::new(-150., 200.),
+        color: Color::WHITE,
+        ..Default::default()
+    });
+}
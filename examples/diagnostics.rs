@@ -0,0 +1,48 @@
+use bevy::{diagnostic::LogDiagnosticsPlugin, prelude::*};
+use jabu_debug_draw::prelude::*;
+
+fn main() {
+    App::new()
+        .insert_resource(ClearColor(Color::rgb(0.1, 0.1, 0.1)))
+        .add_plugins(DefaultPlugins)
+        .add_plugin(DebugDrawPlugin)
+        .add_plugin(DebugDrawDiagnosticsPlugin)
+        .add_plugin(LogDiagnosticsPlugin::default())
+        .add_startup_system(setup)
+        .add_system(draw)
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2dBundle::default());
+}
+
+fn draw(mut debug_draw: ResMut<DebugDraw>) {
+    for i in 0..100 {
+        debug_draw.draw_in(
+            "circles",
+            DebugCircle {
+                position: Vec2::new((i % 10) as f32 * 60. - 270., (i / 10) as f32 * 60. - 270.),
+                radius: 40.,
+                color: Color::WHITE,
+                ..Default::default()
+            },
+        );
+    }
+
+    let stats = debug_draw.stats();
+    let text = format!(
+        "{} draws, {} vertices, {} indices",
+        stats.draws, stats.vertices, stats.indices
+    );
+    debug_draw.draw_in(
+        "text",
+        DebugText {
+            text,
+            position: Vec2::new(0., 320.),
+            scale: 2.,
+            color: Color::WHITE,
+            ..Default::default()
+        },
+    );
+}
@@ -0,0 +1,82 @@
+use bevy::prelude::*;
+use jabu_debug_draw::prelude::*;
+
+fn main() {
+    App::new()
+        .insert_resource(ClearColor(Color::rgb(0.1, 0.1, 0.1)))
+        .add_plugins(DefaultPlugins)
+        .add_plugin(DebugDraw3dPlugin)
+        .add_startup_system(setup)
+        .add_system(orbit_camera)
+        .add_system(draw)
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera3dBundle {
+        transform: Transform::from_xyz(0., 3., 8.).looking_at(Vec3::ZERO, Vec3::Y),
+        ..Default::default()
+    });
+}
+
+fn orbit_camera(time: Res<Time>, mut camera_query: Query<&mut Transform, With<Camera3d>>) {
+    let angle = time.elapsed_seconds() * 0.3;
+    for mut transform in camera_query.iter_mut() {
+        *transform = Transform::from_xyz(angle.sin() * 8., 3., angle.cos() * 8.)
+            .looking_at(Vec3::ZERO, Vec3::Y);
+    }
+}
+
+fn draw(mut debug_draw: ResMut<DebugDraw3d>) {
+    for (axis, color) in [
+        (Vec3::X, Color::RED),
+        (Vec3::Y, Color::GREEN),
+        (Vec3::Z, Color::BLUE),
+    ] {
+        debug_draw.draw_on_top(DebugArrow3d {
+            to: axis,
+            color,
+            ..Default::default()
+        });
+    }
+
+    debug_draw.draw(DebugBox {
+        position: Vec3::new(-2.5, 0.5, 0.),
+        color: Color::rgba(1., 1., 1., 0.5),
+        ..Default::default()
+    });
+    debug_draw.draw(DebugBox {
+        position: Vec3::new(-2.5, 0.5, 0.),
+        color: Color::WHITE,
+        wireframe: true,
+        ..Default::default()
+    });
+
+    debug_draw.draw(DebugSphere {
+        position: Vec3::new(2.5, 0.5, 0.),
+        color: Color::ORANGE,
+        ..Default::default()
+    });
+
+    debug_draw.draw(DebugCylinder {
+        from: Vec3::new(0., 0., -2.5),
+        to: Vec3::new(0., 1.5, -2.5),
+        color: Color::CYAN,
+        wireframe: true,
+        ..Default::default()
+    });
+
+    debug_draw.draw(DebugLine3d {
+        from: Vec3::new(-2.5, 0.5, 0.),
+        to: Vec3::new(2.5, 0.5, 0.),
+        color: Color::YELLOW.into(),
+        ..Default::default()
+    });
+
+    debug_draw.draw_on_top(DebugText3d {
+        text: "Hello 3D!".to_owned(),
+        position: Vec3::new(0., 2., 0.),
+        color: Color::WHITE,
+        ..Default::default()
+    });
+}
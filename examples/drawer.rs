@@ -0,0 +1,58 @@
+use bevy::prelude::*;
+use jabu_debug_draw::prelude::*;
+
+fn main() {
+    App::new()
+        .insert_resource(ClearColor(Color::rgb(0.1, 0.1, 0.1)))
+        .add_plugins(DefaultPlugins)
+        .add_plugin(DebugDrawPlugin)
+        .add_startup_system(setup)
+        // These systems don't conflict, so they can run in parallel.
+        .add_system(draw_circles)
+        .add_system(draw_rectangles)
+        .add_system(draw_lines)
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2dBundle::default());
+}
+
+fn draw_circles(mut debug_drawer: DebugDrawer, time: Res<Time>) {
+    for index in 0..10 {
+        let angle = time.elapsed_seconds() + index as f32 * 0.6;
+        debug_drawer.draw(DebugCircle {
+            position: Vec2::from_angle(angle) * 250.,
+            radius: 40.,
+            color: Color::ORANGE,
+            ..Default::default()
+        });
+    }
+}
+
+fn draw_rectangles(mut debug_drawer: DebugDrawer, time: Res<Time>) {
+    debug_drawer.set_blend_mode(DebugDrawBlendMode::Additive);
+    for index in 0..10 {
+        let angle = -time.elapsed_seconds() + index as f32 * 0.6;
+        debug_drawer.draw(DebugRectangle {
+            position: Vec2::from_angle(angle) * 150.,
+            size: Vec2::splat(50.),
+            rotation: angle,
+            color: Color::CYAN,
+            ..Default::default()
+        });
+    }
+}
+
+fn draw_lines(mut debug_drawer: DebugDrawer) {
+    for index in 0..10 {
+        let x = -270. + index as f32 * 60.;
+        debug_drawer.draw(DebugLine {
+            from: Vec2::new(x, -300.),
+            to: Vec2::new(x, 300.),
+            thickness: 1.,
+            color: Color::GRAY.into(),
+            depth: -1.,
+        });
+    }
+}
@@ -0,0 +1,52 @@
+use bevy::prelude::*;
+use jabu_debug_draw::prelude::*;
+
+fn main() {
+    App::new()
+        .insert_resource(ClearColor(Color::rgb(0.1, 0.1, 0.1)))
+        .insert_resource(Msaa::Off)
+        .add_plugins(DefaultPlugins)
+        .add_plugin(DebugDrawPlugin)
+        .add_startup_system(setup)
+        .add_system(zoom)
+        .add_system(draw)
+        .run();
+}
+
+fn setup(mut commands: Commands, mut debug_draw: ResMut<DebugDraw>) {
+    commands.spawn(Camera2dBundle::default());
+    debug_draw.set_feathering(Some(1.));
+}
+
+fn zoom(time: Res<Time>, mut projection_query: Query<&mut OrthographicProjection>) {
+    for mut projection in projection_query.iter_mut() {
+        projection.scale = 1. + time.elapsed_seconds().sin() * 0.8;
+    }
+}
+
+fn draw(mut debug_draw: ResMut<DebugDraw>, keys: Res<Input<KeyCode>>) {
+    if keys.just_pressed(KeyCode::F) {
+        let feathering = debug_draw.feathering();
+        debug_draw.set_feathering(if feathering.is_some() { None } else { Some(1.) });
+    }
+
+    debug_draw.draw(DebugLine {
+        from: Vec2::new(-300., -100.),
+        to: Vec2::new(-100., 100.),
+        thickness: 2.,
+        color: Color::WHITE.into(),
+        ..Default::default()
+    });
+    debug_draw.draw(DebugCircle {
+        position: Vec2::new(100., 0.),
+        radius: 150.,
+        color: Color::ORANGE,
+        ..Default::default()
+    });
+    debug_draw.draw(DebugText {
+        text: "F: toggle feathering".to_owned(),
+        position: Vec2::new(-300., 200.),
+        color: Color::WHITE,
+        ..Default::default()
+    });
+}
@@ -0,0 +1,92 @@
+use bevy::{prelude::*, transform::TransformSystem};
+use jabu_debug_draw::prelude::*;
+
+fn main() {
+    App::new()
+        .insert_resource(ClearColor(Color::rgb(0.1, 0.1, 0.1)))
+        .insert_resource(FixedTime::new_from_secs(0.1))
+        .add_plugins(DefaultPlugins)
+        .add_plugin(DebugDrawPlugin)
+        .add_startup_system(setup)
+        .add_system(
+            simulate
+                .in_schedule(CoreSchedule::FixedUpdate)
+                .after(DebugDrawFixedSystem),
+        )
+        .add_system(spin)
+        .add_system(
+            draw_children
+                .in_base_set(CoreSet::PostUpdate)
+                .after(TransformSystem::TransformPropagate)
+                .before(DebugDrawSystem),
+        )
+        .run();
+}
+
+#[derive(Component)]
+struct Spinner;
+
+#[derive(Component)]
+struct Marker;
+
+#[derive(Resource, Default)]
+struct Ball {
+    position: Vec2,
+    velocity: Vec2,
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2dBundle::default());
+    commands.insert_resource(Ball {
+        position: Vec2::ZERO,
+        velocity: Vec2::new(120., 80.),
+    });
+    commands
+        .spawn((SpatialBundle::default(), Spinner))
+        .with_children(|parent| {
+            parent.spawn((
+                SpatialBundle::from_transform(Transform::from_xyz(200., 0., 0.)),
+                Marker,
+            ));
+        });
+}
+
+/// Runs ten times per second, whatever the frame rate, and draws once per tick.
+fn simulate(mut ball: ResMut<Ball>, fixed_time: Res<FixedTime>, mut debug_draw: ResMut<DebugDraw>) {
+    let delta = fixed_time.period.as_secs_f32();
+    let velocity = ball.velocity;
+    ball.position += velocity * delta;
+    if ball.position.x.abs() > 300. {
+        ball.velocity.x *= -1.;
+    }
+    if ball.position.y.abs() > 200. {
+        ball.velocity.y *= -1.;
+    }
+    debug_draw.draw(DebugCircle {
+        position: ball.position,
+        radius: 40.,
+        color: Color::ORANGE,
+        ..Default::default()
+    });
+}
+
+fn spin(time: Res<Time>, mut spinner_query: Query<&mut Transform, With<Spinner>>) {
+    for mut transform in spinner_query.iter_mut() {
+        transform.rotation = Quat::from_rotation_z(time.elapsed_seconds());
+    }
+}
+
+/// Uses this frame's global transforms, so the circle sticks to the marker.
+fn draw_children(
+    mut debug_draw: ResMut<DebugDraw>,
+    marker_query: Query<&GlobalTransform, With<Marker>>,
+) {
+    for transform in marker_query.iter() {
+        debug_draw.draw(DebugCircle {
+            position: transform.translation().truncate(),
+            radius: 30.,
+            color: Color::CYAN,
+            ..Default::default()
+        });
+    }
+}
@@ -0,0 +1,62 @@
+use std::{env, fs};
+
+use bevy::prelude::*;
+use jabu_debug_draw::prelude::*;
+
+// Pass the path of a TTF or OTF file to draw with it, for example:
+// cargo run --example fonts -- /usr/share/fonts/truetype/dejavu/DejaVuSansMono.ttf
+fn main() {
+    App::new()
+        .insert_resource(ClearColor(Color::rgb(0.1, 0.1, 0.1)))
+        .add_plugins(DefaultPlugins)
+        .add_plugin(DebugDrawPlugin)
+        .add_startup_system(setup)
+        .add_system(draw)
+        .run();
+}
+
+#[derive(Resource)]
+struct CustomFont(DebugTextFont);
+
+fn setup(mut commands: Commands, mut fonts: ResMut<DebugTextFonts>) {
+    commands.spawn(Camera2dBundle::default());
+
+    let font = env::args()
+        .nth(1)
+        .and_then(|path| match fs::read(&path) {
+            Ok(data) => fonts.add_bytes(data).ok(),
+            Err(error) => {
+                error!("Failed to read {}: {}", path, error);
+                None
+            }
+        })
+        .unwrap_or_default();
+    commands.insert_resource(CustomFont(font));
+}
+
+fn draw(mut debug_draw: ResMut<DebugDraw>, font: Res<CustomFont>, time: Res<Time>) {
+    let lines = [
+        "unit      hp   x",
+        "grunt    100  12",
+        "archer    45 130",
+        "mage      30  -4",
+    ];
+    for (index, text) in lines.iter().enumerate() {
+        debug_draw.draw(DebugText {
+            text: text.to_string(),
+            position: Vec2::new(-250., 100. - index as f32 * 40.),
+            scale: 1.5,
+            color: Color::WHITE,
+            font: font.0.clone(),
+            ..Default::default()
+        });
+    }
+    debug_draw.draw(DebugText {
+        text: format!("{:8.2}", time.elapsed_seconds()),
+        position: Vec2::new(-250., 200.),
+        scale: 1.5,
+        color: Color::YELLOW,
+        font: font.0.clone(),
+        ..Default::default()
+    });
+}
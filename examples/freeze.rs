@@ -0,0 +1,40 @@
+use bevy::prelude::*;
+use jabu_debug_draw::prelude::*;
+
+fn main() {
+    App::new()
+        .insert_resource(ClearColor(Color::rgb(0.1, 0.1, 0.1)))
+        .add_plugins(DefaultPlugins)
+        .add_plugin(DebugDrawPlugin)
+        .add_plugin(DebugDrawFreezePlugin)
+        .add_startup_system(setup)
+        .add_system(draw)
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2dBundle::default());
+}
+
+fn draw(mut debug_draw: ResMut<DebugDraw>, time: Res<Time>) {
+    debug_draw.draw_screen(
+        DebugDrawAnchor::Top,
+        DebugText {
+            text: "F9: freeze, F8: step, F7: reset view\nMiddle mouse: pan, wheel: zoom".into(),
+            position: Vec2::new(0., -40.),
+            scale: 1.5,
+            color: Color::WHITE,
+            ..Default::default()
+        },
+    );
+
+    for i in 0..20 {
+        let t = time.elapsed_seconds() * 2. + i as f32 * 0.3;
+        debug_draw.draw(DebugCircle {
+            position: Vec2::new(t.cos() * 300., (t * 1.7).sin() * 200.),
+            radius: 20.,
+            color: Color::hsl(i as f32 * 18., 0.8, 0.6),
+            ..Default::default()
+        });
+    }
+}
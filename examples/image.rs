@@ -0,0 +1,70 @@
+use bevy::{
+    prelude::*,
+    render::render_resource::{Extent3d, TextureDimension, TextureFormat},
+};
+use jabu_debug_draw::prelude::*;
+
+fn main() {
+    App::new()
+        .insert_resource(ClearColor(Color::rgb(0.1, 0.1, 0.1)))
+        .add_plugins(DefaultPlugins)
+        .add_plugin(DebugDrawPlugin)
+        .add_startup_system(setup)
+        .add_system(draw)
+        .run();
+}
+
+#[derive(Resource)]
+struct Heightmap(Handle<Image>);
+
+fn setup(mut commands: Commands, mut images: ResMut<Assets<Image>>) {
+    commands.spawn(Camera2dBundle::default());
+
+    const SIZE: u32 = 64;
+    let mut data = Vec::with_capacity((SIZE * SIZE * 4) as usize);
+    for y in 0..SIZE {
+        for x in 0..SIZE {
+            let height = ((x as f32 * 0.2).sin() * (y as f32 * 0.15).cos() * 0.5 + 0.5) * 255.;
+            data.extend_from_slice(&[height as u8, height as u8, height as u8, 255]);
+        }
+    }
+    let image = Image::new(
+        Extent3d {
+            width: SIZE,
+            height: SIZE,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        data,
+        TextureFormat::Rgba8UnormSrgb,
+    );
+    commands.insert_resource(Heightmap(images.add(image)));
+}
+
+fn draw(mut debug_draw: ResMut<DebugDraw>, heightmap: Res<Heightmap>, time: Res<Time>) {
+    debug_draw.draw(DebugImage {
+        image: heightmap.0.clone(),
+        position: Vec2::new(-200., 0.),
+        size: Vec2::splat(256.),
+        ..Default::default()
+    });
+
+    // The top left quarter, tinted and spinning.
+    debug_draw.draw(DebugImage {
+        image: heightmap.0.clone(),
+        position: Vec2::new(200., 0.),
+        size: Vec2::splat(256.),
+        uv_rect: Rect::new(0., 0., 0.5, 0.5),
+        rotation: time.elapsed_seconds(),
+        tint: Color::ORANGE,
+        depth: 1.,
+    });
+
+    debug_draw.draw(DebugCircle {
+        position: Vec2::new(200., 0.),
+        radius: 50.,
+        color: Color::CYAN,
+        depth: 2.,
+        ..Default::default()
+    });
+}
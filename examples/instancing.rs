@@ -0,0 +1,90 @@
+use std::f32::consts::TAU;
+
+use bevy::prelude::*;
+use jabu_debug_draw::{prelude::*, DebugLineColor};
+use rand::prelude::*;
+
+const WINDOW_WIDTH: f32 = 1380.;
+const WINDOW_HEIGHT: f32 = 820.;
+
+fn main() {
+    App::new()
+        .insert_resource(ClearColor(Color::rgb(0.1, 0.1, 0.1)))
+        .add_plugins(DefaultPlugins)
+        .add_plugin(DebugDrawPlugin)
+        .add_plugin(DebugDrawInstancingPlugin)
+        .add_startup_system(setup)
+        .add_system(draw)
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2dBundle::default());
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct Particle {
+    position: Vec2,
+    velocity: Vec2,
+    hue: f32,
+}
+
+struct Draw {
+    particles: Vec<Particle>,
+}
+
+impl Default for Draw {
+    fn default() -> Self {
+        let mut rng = thread_rng();
+        let mut particles = vec![];
+        for _ in 0..20000 {
+            let x = rng.gen_range((WINDOW_WIDTH * -0.5)..(WINDOW_WIDTH * 0.5));
+            let y = rng.gen_range((WINDOW_HEIGHT * -0.5)..(WINDOW_HEIGHT * 0.5));
+            let angle = rng.gen_range(0.0..TAU);
+            let speed = rng.gen_range(10.0..=50.0);
+            particles.push(Particle {
+                position: Vec2::new(x, y),
+                velocity: Vec2::from_angle(angle).rotate(Vec2::X * speed),
+                hue: rng.gen_range(0.0..360.0),
+            });
+        }
+        Self { particles }
+    }
+}
+
+fn draw(mut local: Local<Draw>, mut debug_draw: ResMut<DebugDraw>, time: Res<Time>) {
+    for particle in local.particles.iter_mut() {
+        particle.position += particle.velocity * time.delta_seconds();
+        if particle.position.x < WINDOW_WIDTH * -0.5 {
+            particle.position.x = WINDOW_WIDTH * 0.5;
+        }
+        if particle.position.x > WINDOW_WIDTH * 0.5 {
+            particle.position.x = WINDOW_WIDTH * -0.5;
+        }
+        if particle.position.y < WINDOW_HEIGHT * -0.5 {
+            particle.position.y = WINDOW_HEIGHT * 0.5;
+        }
+        if particle.position.y > WINDOW_HEIGHT * 0.5 {
+            particle.position.y = WINDOW_HEIGHT * -0.5;
+        }
+    }
+    for particle in local.particles.iter() {
+        debug_draw.draw(DebugCircle {
+            position: particle.position,
+            radius: 6.,
+            segments: 16,
+            color: Color::hsla(particle.hue, 0.8, 0.6, 0.8),
+            ..Default::default()
+        });
+        debug_draw.draw(DebugLine {
+            from: particle.position,
+            to: particle.position - particle.velocity * 0.2,
+            thickness: 2.,
+            color: DebugLineColor::Gradient(
+                Color::hsla(particle.hue, 0.8, 0.6, 0.8),
+                Color::hsla(particle.hue, 0.8, 0.6, 0.),
+            ),
+            ..Default::default()
+        });
+    }
+}
@@ -0,0 +1,30 @@
+use bevy::prelude::*;
+use jabu_debug_draw::prelude::*;
+
+fn main() {
+    App::new()
+        .insert_resource(ClearColor(Color::rgb(0.1, 0.1, 0.1)))
+        .add_plugins(DefaultPlugins)
+        .add_plugin(DebugDrawPlugin)
+        .add_startup_system(setup)
+        .add_system(draw)
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2dBundle::default());
+}
+
+fn draw(mut debug_draw: ResMut<DebugDraw>) {
+    for (index, kerning) in [false, true].into_iter().enumerate() {
+        debug_draw.draw(DebugText {
+            text: "AVATAR To Ty".to_owned(),
+            position: Vec2::new(-500., 200. - index as f32 * 250.),
+            scale: 6.,
+            color: Color::WHITE,
+            kerning,
+            background: Some(Color::rgba(0., 0., 0., 0.6)),
+            ..Default::default()
+        });
+    }
+}
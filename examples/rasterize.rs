@@ -0,0 +1,38 @@
+use bevy::prelude::*;
+use jabu_debug_draw::{prelude::*, DebugDrawDrawable};
+
+fn main() {
+    let meshes = [
+        DebugRectangle {
+            position: Vec2::new(-200., 0.),
+            size: Vec2::new(300., 300.),
+            color: Color::RED,
+            ..Default::default()
+        }
+        .to_mesh(),
+        DebugCircle {
+            position: Vec2::new(0., 0.),
+            radius: 200.,
+            color: Color::rgba(0., 0., 1., 0.5),
+            depth: 1.,
+            ..Default::default()
+        }
+        .to_mesh(),
+        DebugLine {
+            from: Vec2::new(-500., -300.),
+            to: Vec2::new(500., 300.),
+            thickness: 10.,
+            color: Color::WHITE.into(),
+            depth: 2.,
+        }
+        .to_mesh(),
+    ];
+
+    let rasterizer = DebugDrawRasterizer {
+        background: Color::rgb(0.1, 0.1, 0.1),
+        ..Default::default()
+    };
+    let pixels = rasterizer.rasterize(&meshes).unwrap();
+    pixels.save_png("rasterize.png").unwrap();
+    println!("center pixel: {:?}", pixels.get(640, 360));
+}
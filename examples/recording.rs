@@ -0,0 +1,46 @@
+use bevy::prelude::*;
+use jabu_debug_draw::prelude::*;
+
+// Press R to start or stop recording to recording.jsonl, then convert it with:
+// cargo run --features raster,recording --bin debug_draw_replay -- recording.jsonl png frames
+fn main() {
+    App::new()
+        .insert_resource(ClearColor(Color::rgb(0.1, 0.1, 0.1)))
+        .add_plugins(DefaultPlugins)
+        .add_plugin(DebugDrawPlugin)
+        .add_plugin(DebugDrawRecorderPlugin)
+        .add_startup_system(setup)
+        .add_system(toggle_recording)
+        .add_system(draw)
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2dBundle::default());
+}
+
+fn toggle_recording(keys: Res<Input<KeyCode>>, mut recorder: ResMut<DebugDrawRecorder>) {
+    if keys.just_pressed(KeyCode::R) {
+        let result = if recorder.is_recording() {
+            recorder.stop()
+        } else {
+            recorder.start("recording.jsonl")
+        };
+        if let Err(error) = result {
+            error!("{}", error);
+        }
+    }
+}
+
+fn draw(mut debug_draw: ResMut<DebugDraw>, recorder: Res<DebugDrawRecorder>, time: Res<Time>) {
+    debug_draw.draw(DebugCircle {
+        position: Vec2::from_angle(time.elapsed_seconds()) * 200.,
+        radius: 50.,
+        color: if recorder.is_recording() {
+            Color::RED
+        } else {
+            Color::WHITE
+        },
+        ..Default::default()
+    });
+}
@@ -0,0 +1,91 @@
+use std::{env, thread, time::Duration};
+
+use bevy::{app::ScheduleRunnerSettings, prelude::*};
+use jabu_debug_draw::prelude::*;
+
+// Without arguments, a headless source runs on another thread and sends its drawing through a
+// loopback. Use `server <address>` and `viewer <address>` to run them as separate processes.
+fn main() {
+    let args = env::args().skip(1).collect::<Vec<_>>();
+    match args.iter().map(String::as_str).collect::<Vec<_>>()[..] {
+        ["server", address] => server(DebugDrawRemoteEndpoint::Tcp(address.to_owned())),
+        ["viewer", address] => viewer(DebugDrawRemoteEndpoint::Tcp(address.to_owned())),
+        _ => {
+            let loopback = DebugDrawLoopback::new();
+            let endpoint = DebugDrawRemoteEndpoint::Loopback(loopback.clone());
+            thread::spawn(move || server(endpoint));
+            viewer(DebugDrawRemoteEndpoint::Loopback(loopback));
+        }
+    }
+}
+
+fn server(endpoint: DebugDrawRemoteEndpoint) {
+    App::new()
+        .insert_resource(ScheduleRunnerSettings::run_loop(Duration::from_secs_f64(
+            1. / 60.,
+        )))
+        .add_plugins(MinimalPlugins)
+        .add_plugin(DebugDrawHeadlessPlugin)
+        .add_plugin(DebugDrawRemoteSourcePlugin { endpoint })
+        .add_system(server_draw)
+        .run();
+}
+
+fn server_draw(mut debug_draw: ResMut<DebugDraw>, time: Res<Time>) {
+    for i in 0..5 {
+        let angle = time.elapsed_seconds() + i as f32 * 1.2566;
+        debug_draw.draw(DebugCircle {
+            position: Vec2::from_angle(angle) * 200.,
+            radius: 40.,
+            color: Color::WHITE,
+            ..Default::default()
+        });
+    }
+}
+
+fn viewer(endpoint: DebugDrawRemoteEndpoint) {
+    App::new()
+        .insert_resource(ClearColor(Color::rgb(0.1, 0.1, 0.1)))
+        .insert_resource(Source(endpoint))
+        .add_plugins(DefaultPlugins)
+        .add_plugin(DebugDrawPlugin)
+        .add_plugin(DebugDrawRemoteViewerPlugin)
+        .add_startup_system(setup)
+        .add_system(toggle)
+        .run();
+}
+
+#[derive(Resource)]
+struct Source(DebugDrawRemoteEndpoint);
+
+#[derive(Resource)]
+struct SourceCategory(DebugDrawCategory);
+
+fn setup(
+    mut commands: Commands,
+    source: Res<Source>,
+    mut remote: ResMut<DebugDrawRemote>,
+    mut debug_draw: ResMut<DebugDraw>,
+) {
+    commands.spawn(Camera2dBundle::default());
+    let category = remote.connect("server", source.0.clone());
+    debug_draw.category_settings_mut(category.clone()).tint = Some(Color::ORANGE);
+    commands.insert_resource(SourceCategory(category));
+}
+
+fn toggle(
+    keys: Res<Input<KeyCode>>,
+    category: Res<SourceCategory>,
+    mut debug_draw: ResMut<DebugDraw>,
+) {
+    debug_draw.draw(DebugText {
+        text: "Press Space to toggle the server drawing".into(),
+        position: Vec2::new(0., 300.),
+        scale: 2.,
+        color: Color::WHITE,
+        ..Default::default()
+    });
+    if keys.just_pressed(KeyCode::Space) {
+        debug_draw.toggle_category(category.0.clone());
+    }
+}
@@ -0,0 +1,62 @@
+use bevy::prelude::*;
+use jabu_debug_draw::prelude::*;
+
+fn main() {
+    App::new()
+        .insert_resource(ClearColor(Color::rgb(0.1, 0.1, 0.1)))
+        .add_plugins(DefaultPlugins)
+        .add_plugin(DebugDrawPlugin)
+        .add_startup_system(setup)
+        .add_system(draw)
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2dBundle::default());
+}
+
+fn draw(
+    mut debug_draw: ResMut<DebugDraw>,
+    mut camera_query: Query<(&mut Transform, &mut OrthographicProjection), With<Camera2d>>,
+    time: Res<Time>,
+) {
+    for (mut camera_transform, mut camera_projection) in camera_query.iter_mut() {
+        camera_transform.translation.x = time.elapsed_seconds().sin() * 200.;
+        camera_projection.scale = 1.5 + time.elapsed_seconds().cos() * 0.5;
+    }
+
+    debug_draw.draw(DebugRectangle {
+        size: Vec2::new(400., 400.),
+        color: Color::WHITE,
+        ..Default::default()
+    });
+
+    debug_draw.draw_screen(
+        DebugDrawAnchor::TopLeft,
+        DebugText {
+            text: format!("elapsed: {:.2}", time.elapsed_seconds()),
+            position: Vec2::new(10., -10.),
+            color: Color::YELLOW,
+            ..Default::default()
+        },
+    );
+    debug_draw.draw_screen(
+        DebugDrawAnchor::BottomRight,
+        DebugCircle {
+            position: Vec2::new(-60., 60.),
+            radius: 100.,
+            color: Color::RED,
+            ..Default::default()
+        },
+    );
+    debug_draw.draw_screen(
+        DebugDrawAnchor::Center,
+        DebugLine {
+            from: Vec2::new(-20., 0.),
+            to: Vec2::new(20., 0.),
+            thickness: 2.,
+            color: Color::GREEN.into(),
+            ..Default::default()
+        },
+    );
+}
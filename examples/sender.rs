@@ -0,0 +1,68 @@
+use bevy::{prelude::*, tasks::AsyncComputeTaskPool};
+use jabu_debug_draw::{global_debug_draw, prelude::*};
+
+fn main() {
+    App::new()
+        .insert_resource(ClearColor(Color::rgb(0.1, 0.1, 0.1)))
+        .add_plugins(DefaultPlugins)
+        .add_plugin(DebugDrawPlugin)
+        .add_startup_system(setup)
+        .add_system(spawn_search)
+        .run();
+}
+
+fn setup(mut commands: Commands, debug_draw: Res<DebugDraw>) {
+    commands.spawn(Camera2dBundle::default());
+    debug_draw.install_global_sender();
+}
+
+/// Starts a fake search on a background thread every few seconds, showing each visited cell as
+/// it goes.
+fn spawn_search(debug_draw: Res<DebugDraw>, time: Res<Time>, mut cooldown: Local<f32>) {
+    *cooldown -= time.delta_seconds();
+    if *cooldown > 0. {
+        return;
+    }
+    *cooldown = 3.;
+
+    let sender = debug_draw.sender();
+    AsyncComputeTaskPool::get()
+        .spawn(async move {
+            for step in 0..100u32 {
+                let cell = Vec2::new((step % 10) as f32, (step / 10) as f32);
+                sender.draw_for(
+                    DebugDrawDuration::Seconds(2.),
+                    true,
+                    DebugRectangle {
+                        position: (cell - 4.5) * 40.,
+                        size: Vec2::splat(36.),
+                        color: Color::ORANGE,
+                        ..Default::default()
+                    },
+                );
+                std::thread::sleep(std::time::Duration::from_millis(10));
+            }
+            other_module::report_done();
+        })
+        .detach();
+}
+
+mod other_module {
+    use super::*;
+
+    /// Code with no access to the ECS can still draw through the global sender.
+    pub fn report_done() {
+        if let Some(sender) = global_debug_draw() {
+            sender.draw_for(
+                DebugDrawDuration::Seconds(1.),
+                false,
+                DebugText {
+                    text: "done".to_owned(),
+                    position: Vec2::new(-40., 260.),
+                    color: Color::WHITE,
+                    ..Default::default()
+                },
+            );
+        }
+    }
+}
@@ -0,0 +1,49 @@
+use bevy::prelude::*;
+use jabu_debug_draw::prelude::*;
+
+fn main() {
+    App::new()
+        .insert_resource(ClearColor(Color::rgb(0.1, 0.1, 0.1)))
+        .add_plugins(DefaultPlugins)
+        .add_plugin(DebugDrawPlugin)
+        .add_startup_system(setup)
+        .add_system(draw)
+        .run();
+}
+
+fn setup(mut commands: Commands, mut debug_draw: ResMut<DebugDraw>) {
+    commands.spawn(Camera2dBundle::default());
+    debug_draw.set_depth_mapping(DebugDrawDepthMapping::z());
+
+    for (x, z) in [(-100., 0.5), (100., 5.)] {
+        commands.spawn(SpriteBundle {
+            sprite: Sprite {
+                color: Color::GRAY,
+                custom_size: Some(Vec2::new(150., 300.)),
+                ..Default::default()
+            },
+            transform: Transform::from_xyz(x, 0., z),
+            ..Default::default()
+        });
+    }
+}
+
+fn draw(mut debug_draw: ResMut<DebugDraw>, time: Res<Time>) {
+    let x = time.elapsed_seconds().sin() * 250.;
+    // Between the two sprites: in front of the left one, behind the right one.
+    debug_draw.draw(DebugCircle {
+        position: Vec2::new(x, 50.),
+        radius: 80.,
+        color: Color::ORANGE,
+        depth: 2.,
+        ..Default::default()
+    });
+    // In front of both sprites.
+    debug_draw.draw(DebugCircle {
+        position: Vec2::new(-x, -50.),
+        radius: 80.,
+        color: Color::CYAN,
+        depth: 10.,
+        ..Default::default()
+    });
+}
@@ -0,0 +1,51 @@
+use bevy::prelude::*;
+use jabu_debug_draw::prelude::*;
+
+fn main() {
+    App::new()
+        .insert_resource(ClearColor(Color::rgb(0.1, 0.1, 0.1)))
+        .add_plugins(DefaultPlugins)
+        .add_plugin(DebugDrawPlugin)
+        .add_plugin(DebugDrawSvgPlugin)
+        .add_startup_system(setup)
+        .add_system(draw)
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2dBundle::default());
+}
+
+fn draw(mut debug_draw: ResMut<DebugDraw>, time: Res<Time>) {
+    debug_draw.draw(DebugText {
+        text: "Press F10 to save debug_draw.svg".into(),
+        position: Vec2::new(0., 250.),
+        scale: 2.,
+        color: Color::WHITE,
+        ..Default::default()
+    });
+
+    debug_draw.draw(DebugRectangle {
+        position: Vec2::new(-200., 0.),
+        size: Vec2::new(200., 200.),
+        rotation: time.elapsed_seconds(),
+        color: Color::RED,
+        ..Default::default()
+    });
+
+    debug_draw.draw(DebugCircle {
+        position: Vec2::new(0., 0.),
+        radius: 300.,
+        color: Color::rgba(0., 0.5, 1., 0.5),
+        depth: 1.,
+        ..Default::default()
+    });
+
+    debug_draw.draw(DebugLine {
+        from: Vec2::new(-400., -200.),
+        to: Vec2::new(400., 200.),
+        thickness: 4.,
+        color: jabu_debug_draw::DebugLineColor::Gradient(Color::GREEN, Color::YELLOW),
+        depth: 2.,
+    });
+}
@@ -0,0 +1,81 @@
+use bevy::{
+    core_pipeline::clear_color::ClearColorConfig,
+    prelude::*,
+    render::{camera::Viewport, view::RenderLayers},
+    window::PrimaryWindow,
+};
+use jabu_debug_draw::prelude::*;
+
+fn main() {
+    App::new()
+        .insert_resource(ClearColor(Color::rgb(0.1, 0.1, 0.1)))
+        .add_plugins(DefaultPlugins)
+        .add_plugin(DebugDrawPlugin)
+        .add_startup_system(setup)
+        .add_system(draw)
+        .run();
+}
+
+#[derive(Component)]
+struct Minimap;
+
+fn setup(mut commands: Commands, window_query: Query<&Window, With<PrimaryWindow>>) {
+    commands.spawn(Camera2dBundle::default());
+
+    let window = window_query.single();
+    let minimap_size = UVec2::new(300, 200);
+    commands
+        .spawn(Camera2dBundle {
+            camera: Camera {
+                order: 1,
+                viewport: Some(Viewport {
+                    physical_position: UVec2::new(
+                        window.physical_width() - minimap_size.x - 20,
+                        20,
+                    ),
+                    physical_size: minimap_size,
+                    ..Default::default()
+                }),
+                ..Default::default()
+            },
+            camera_2d: Camera2d {
+                clear_color: ClearColorConfig::Custom(Color::rgb(0.2, 0.2, 0.3)),
+            },
+            projection: OrthographicProjection {
+                scale: 4.,
+                ..Default::default()
+            },
+            ..Default::default()
+        })
+        .insert((Minimap, RenderLayers::layer(1)));
+}
+
+fn draw(
+    mut debug_draw: ResMut<DebugDraw>,
+    minimap_query: Query<Entity, With<Minimap>>,
+    time: Res<Time>,
+) {
+    let position = Vec2::from_angle(time.elapsed_seconds()) * 200.;
+
+    debug_draw.draw(DebugCircle {
+        position,
+        radius: 100.,
+        color: Color::WHITE,
+        ..Default::default()
+    });
+
+    let minimap = minimap_query.single();
+    debug_draw.set_target(minimap);
+    debug_draw.draw(DebugCircle {
+        position,
+        radius: 200.,
+        color: Color::YELLOW,
+        ..Default::default()
+    });
+    debug_draw.draw(DebugRectangle {
+        size: Vec2::new(1280., 720.),
+        color: Color::rgba(1., 1., 1., 0.1),
+        ..Default::default()
+    });
+    debug_draw.set_target(DebugDrawTarget::Default);
+}
@@ -0,0 +1,49 @@
+use bevy::prelude::*;
+use jabu_debug_draw::{prelude::*, DebugTextOverflow, DebugTextWrap};
+
+const REASONING: &str = "Evaluating targets:\tplayer (distance 12.5, visible), \
+turret_03 (distance 40.2, occluded). Chose to flank the player through the \
+north_corridor_waypoint_sequence because the direct path crosses the turret's line of fire.";
+
+fn main() {
+    App::new()
+        .insert_resource(ClearColor(Color::rgb(0.1, 0.1, 0.1)))
+        .add_plugins(DefaultPlugins)
+        .add_plugin(DebugDrawPlugin)
+        .add_startup_system(setup)
+        .add_system(draw)
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2dBundle::default());
+}
+
+fn draw(mut debug_draw: ResMut<DebugDraw>, time: Res<Time>) {
+    let max_width = 250. + (time.elapsed_seconds() * 0.5).sin() * 100.;
+
+    debug_draw.draw(DebugText {
+        text: REASONING.to_owned(),
+        position: Vec2::new(-550., 250.),
+        color: Color::WHITE,
+        max_width: Some(max_width),
+        background: Some(Color::rgba(0., 0., 0., 0.6)),
+        padding: 10.,
+        ..Default::default()
+    });
+
+    debug_draw.draw(DebugText {
+        text: REASONING.to_owned(),
+        position: Vec2::new(50., 250.),
+        color: Color::WHITE,
+        max_width: Some(max_width),
+        max_lines: Some(3),
+        overflow: DebugTextOverflow::Ellipsis,
+        wrap: DebugTextWrap::Character,
+        line_spacing: 1.3,
+        letter_spacing: 0.05,
+        background: Some(Color::rgba(0.2, 0., 0.3, 0.6)),
+        padding: 10.,
+        ..Default::default()
+    });
+}
@@ -0,0 +1,77 @@
+use bevy::prelude::*;
+use jabu_debug_draw::prelude::*;
+
+fn main() {
+    App::new()
+        .insert_resource(ClearColor(Color::rgb(0.1, 0.1, 0.1)))
+        .add_plugins(DefaultPlugins)
+        .add_plugin(DebugDrawPlugin)
+        .add_startup_system(setup)
+        .add_system(draw)
+        .run();
+}
+
+#[derive(Resource)]
+struct Cursor(DebugDrawHandle);
+
+fn setup(mut commands: Commands, mut debug_draw: ResMut<DebugDraw>) {
+    commands.spawn(Camera2dBundle::default());
+    commands.insert_resource(Cursor(debug_draw.draw_persistent(DebugCircle {
+        radius: 20.,
+        color: Color::WHITE,
+        ..Default::default()
+    })));
+}
+
+fn draw(
+    mut debug_draw: ResMut<DebugDraw>,
+    cursor: Res<Cursor>,
+    mouse: Res<Input<MouseButton>>,
+    window_query: Query<&Window>,
+    camera_query: Query<(&Camera, &GlobalTransform)>,
+) {
+    let (Ok(window), Ok((camera, camera_transform))) =
+        (window_query.get_single(), camera_query.get_single())
+    else {
+        return;
+    };
+    let Some(position) = window
+        .cursor_position()
+        .and_then(|position| camera.viewport_to_world_2d(camera_transform, position))
+    else {
+        return;
+    };
+
+    debug_draw.update_persistent(
+        cursor.0,
+        DebugCircle {
+            position,
+            radius: 20.,
+            color: Color::WHITE,
+            ..Default::default()
+        },
+    );
+
+    if mouse.just_pressed(MouseButton::Left) {
+        debug_draw.draw_fading(
+            DebugDrawDuration::Seconds(2.),
+            DebugCircle {
+                position,
+                radius: 40.,
+                color: Color::ORANGE,
+                ..Default::default()
+            },
+        );
+    }
+    if mouse.just_pressed(MouseButton::Right) {
+        debug_draw.draw_for(
+            DebugDrawDuration::Frames(30),
+            DebugRectangle {
+                position,
+                size: Vec2::splat(40.),
+                color: Color::CYAN,
+                ..Default::default()
+            },
+        );
+    }
+}
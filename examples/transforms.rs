@@ -0,0 +1,68 @@
+use bevy::prelude::*;
+use jabu_debug_draw::prelude::*;
+
+fn main() {
+    App::new()
+        .insert_resource(ClearColor(Color::rgb(0.1, 0.1, 0.1)))
+        .add_plugins(DefaultPlugins)
+        .add_plugin(DebugDrawPlugin)
+        .add_startup_system(setup)
+        .add_system(fly)
+        .add_system(draw)
+        .run();
+}
+
+#[derive(Component)]
+struct Ship;
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2dBundle::default());
+    commands.spawn((SpatialBundle::default(), Ship));
+}
+
+fn fly(time: Res<Time>, mut ship_query: Query<&mut Transform, With<Ship>>) {
+    let angle = time.elapsed_seconds() * 0.5;
+    for mut transform in ship_query.iter_mut() {
+        transform.translation = Vec3::new(angle.cos() * 200., angle.sin() * 200., 0.);
+        transform.rotation = Quat::from_rotation_z(angle);
+    }
+}
+
+fn draw(
+    mut debug_draw: ResMut<DebugDraw>,
+    ship_query: Query<Entity, With<Ship>>,
+    transform_query: Query<&GlobalTransform>,
+) {
+    for ship in ship_query.iter() {
+        if !debug_draw.push_entity_transform(ship, &transform_query) {
+            continue;
+        }
+        debug_draw.draw(DebugTriangle {
+            points: [
+                Vec2::new(0., 30.),
+                Vec2::new(-20., -20.),
+                Vec2::new(20., -20.),
+            ],
+            color: Color::WHITE,
+            ..Default::default()
+        });
+        for hardpoint in [Vec2::new(-15., -10.), Vec2::new(15., -10.)] {
+            let mut debug_draw =
+                debug_draw.scoped_transform(Transform::from_translation(hardpoint.extend(0.)));
+            debug_draw.draw(DebugCircle {
+                radius: 10.,
+                color: Color::ORANGE,
+                depth: 1.,
+                ..Default::default()
+            });
+            debug_draw.draw(DebugLine {
+                to: Vec2::new(0., 40.),
+                thickness: 2.,
+                color: Color::RED.into(),
+                depth: 1.,
+                ..Default::default()
+            });
+        }
+        debug_draw.pop_transform();
+    }
+}
@@ -0,0 +1,66 @@
+use bevy::prelude::*;
+
+use crate::{ring_point, DebugDrawDrawable3d, DebugDrawMesh3d, DebugDrawVertex3d};
+
+const HEAD_SEGMENTS: u8 = 16;
+
+#[derive(Clone, Copy, Debug)]
+pub struct DebugArrow3d {
+    pub from: Vec3,
+    pub to: Vec3,
+    pub color: Color,
+    pub thickness: f32,
+    pub head_length: f32,
+    pub head_radius: f32,
+}
+
+impl Default for DebugArrow3d {
+    fn default() -> Self {
+        Self {
+            from: Vec3::ZERO,
+            to: Vec3::ZERO,
+            color: Color::BLACK,
+            thickness: 0.02,
+            head_length: 0.2,
+            head_radius: 0.06,
+        }
+    }
+}
+
+impl DebugDrawDrawable3d for DebugArrow3d {
+    fn to_mesh_3d(&self) -> DebugDrawMesh3d {
+        let mut mesh = DebugDrawMesh3d::new();
+        if self.from == self.to {
+            return mesh;
+        }
+        let length = (self.to - self.from).length();
+        let direction = (self.to - self.from) / length;
+        let head_length = self.head_length.min(length);
+        let head_base = self.to - direction * head_length;
+        mesh.add_segment(self.from, head_base, self.thickness, self.color, self.color);
+
+        let base_index = mesh.vertices.len() as u32;
+        let (u, v) = direction.any_orthonormal_pair();
+        mesh.vertices.push(DebugDrawVertex3d {
+            position: self.to,
+            color: self.color,
+        });
+        mesh.vertices.push(DebugDrawVertex3d {
+            position: head_base,
+            color: self.color,
+        });
+        for segment in 0..HEAD_SEGMENTS {
+            mesh.vertices.push(DebugDrawVertex3d {
+                position: ring_point(head_base, u, v, self.head_radius, segment, HEAD_SEGMENTS),
+                color: self.color,
+            });
+        }
+        for segment in 0..HEAD_SEGMENTS as u32 {
+            let a = base_index + 2 + segment;
+            let b = base_index + 2 + (segment + 1) % HEAD_SEGMENTS as u32;
+            mesh.indices
+                .extend_from_slice(&[base_index, a, b, base_index + 1, b, a]);
+        }
+        mesh
+    }
+}
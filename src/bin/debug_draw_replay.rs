@@ -0,0 +1,247 @@
+use std::{env, fs, path::PathBuf, process::exit};
+
+use bevy::prelude::*;
+use jabu_debug_draw::{
+    read_debug_draw_recording, DebugDrawRasterizer, DebugDrawRecordedFrame, DebugDrawSvg,
+};
+
+const USAGE: &str = "\
+Usage: debug_draw_replay <recording> <command> [options]
+
+Commands:
+    summary             Print statistics about the recording
+    svg <directory>     Write every frame as an SVG file
+    png <directory>     Write every frame as a PNG file
+
+Options:
+    --view <min_x,min_y,max_x,max_y>    The area of the world to show, instead of the recorded camera
+    --width <pixels>                    The width of PNG files, 1280 by default
+    --category <name>                   Only keep meshes of the given category
+    --frames <first..end>               Only keep frames from first up to, but excluding, end";
+
+struct Options {
+    recording: PathBuf,
+    command: Command,
+    view: Option<Rect>,
+    width: u32,
+    category: Option<String>,
+    frames: Option<(u64, u64)>,
+}
+
+enum Command {
+    Summary,
+    Svg(PathBuf),
+    Png(PathBuf),
+}
+
+fn main() {
+    let options = parse_options().unwrap_or_else(|error| {
+        eprintln!("{}\n\n{}", error, USAGE);
+        exit(2);
+    });
+    if let Err(error) = run(options) {
+        eprintln!("error: {}", error);
+        exit(1);
+    }
+}
+
+fn parse_options() -> Result<Options, String> {
+    let mut args = env::args().skip(1);
+    let mut positional = vec![];
+    let mut view = None;
+    let mut width = 1280;
+    let mut category = None;
+    let mut frames = None;
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().ok_or(format!("missing value for {}", arg));
+        match arg.as_str() {
+            "--view" => {
+                let bounds = value()?
+                    .split(',')
+                    .map(|bound| bound.trim().parse::<f32>())
+                    .collect::<Result<Vec<_>, _>>()
+                    .map_err(|error| format!("invalid view: {}", error))?;
+                let [min_x, min_y, max_x, max_y] = bounds[..] else {
+                    return Err("the view needs four bounds".into());
+                };
+                let bounds = Rect::new(min_x, min_y, max_x, max_y);
+                if !is_valid_view(bounds) {
+                    return Err("the view needs a positive width and height".into());
+                }
+                view = Some(bounds);
+            }
+            "--width" => {
+                width = value()?
+                    .parse()
+                    .map_err(|error| format!("invalid width: {}", error))?;
+            }
+            "--category" => category = Some(value()?),
+            "--frames" => {
+                let range = value()?;
+                let (first, end) = range
+                    .split_once("..")
+                    .ok_or(format!("invalid frame range: {}", range))?;
+                let parse = |frame: &str| {
+                    frame
+                        .parse::<u64>()
+                        .map_err(|error| format!("invalid frame range: {}", error))
+                };
+                frames = Some((parse(first)?, parse(end)?));
+            }
+            "-h" | "--help" => {
+                println!("{}", USAGE);
+                exit(0);
+            }
+            _ => positional.push(arg),
+        }
+    }
+
+    let mut positional = positional.into_iter();
+    let recording = positional.next().ok_or("missing recording")?.into();
+    let command = match positional.next().as_deref() {
+        Some("summary") => Command::Summary,
+        Some("svg") => Command::Svg(positional.next().ok_or("missing directory")?.into()),
+        Some("png") => Command::Png(positional.next().ok_or("missing directory")?.into()),
+        Some(command) => return Err(format!("unknown command: {}", command)),
+        None => return Err("missing command".into()),
+    };
+    if let Some(arg) = positional.next() {
+        return Err(format!("unexpected argument: {}", arg));
+    }
+    Ok(Options {
+        recording,
+        command,
+        view,
+        width,
+        category,
+        frames,
+    })
+}
+
+fn run(options: Options) -> Result<(), Box<dyn std::error::Error>> {
+    let mut summary = Summary::default();
+    if let Command::Svg(directory) | Command::Png(directory) = &options.command {
+        fs::create_dir_all(directory)?;
+    }
+    for frame in read_debug_draw_recording(&options.recording)? {
+        let mut frame = frame?;
+        if let Some((first, end)) = options.frames {
+            if !(first..end).contains(&frame.frame) {
+                continue;
+            }
+        }
+        if let Some(category) = &options.category {
+            frame
+                .meshes
+                .retain(|mesh| mesh.category.as_ref() == Some(category));
+        }
+        let view = options
+            .view
+            .or(frame.view().filter(|view| is_valid_view(*view)))
+            .unwrap_or(DebugDrawSvg::default().view);
+        match &options.command {
+            Command::Summary => summary.add(&frame),
+            Command::Svg(directory) => {
+                let svg = DebugDrawSvg {
+                    view,
+                    ..Default::default()
+                }
+                .export(frame.world_meshes().iter().map(|(mesh, _)| mesh));
+                fs::write(directory.join(format!("frame_{:06}.svg", frame.frame)), svg)?;
+            }
+            Command::Png(directory) => {
+                let height = (options.width as f32 * view.height() / view.width()).round() as u32;
+                DebugDrawRasterizer {
+                    view,
+                    width: options.width,
+                    height: height.max(1),
+                    ..Default::default()
+                }
+                .rasterize_blended(
+                    frame
+                        .world_meshes()
+                        .iter()
+                        .map(|(mesh, blend_mode)| (mesh, *blend_mode)),
+                )?
+                .save_png(directory.join(format!("frame_{:06}.png", frame.frame)))?;
+            }
+        }
+    }
+    if let Command::Summary = options.command {
+        summary.print();
+    }
+    Ok(())
+}
+
+/// Whether the view has an area, so the PNG height can be derived from its aspect ratio.
+fn is_valid_view(view: Rect) -> bool {
+    let size = view.size();
+    size.x > 0. && size.y > 0. && size.is_finite()
+}
+
+#[derive(Default)]
+struct Summary {
+    frames: u64,
+    first_time: Option<f32>,
+    last_time: f32,
+    meshes: usize,
+    max_meshes: usize,
+    triangles: usize,
+    bounds: Option<Rect>,
+    categories: Vec<(String, usize)>,
+}
+
+impl Summary {
+    fn add(&mut self, frame: &DebugDrawRecordedFrame) {
+        self.frames += 1;
+        self.first_time.get_or_insert(frame.time);
+        self.last_time = frame.time;
+        self.meshes += frame.meshes.len();
+        self.max_meshes = self.max_meshes.max(frame.meshes.len());
+        for mesh in &frame.meshes {
+            self.triangles += mesh.indices.len() / 3;
+            if !mesh.screen {
+                for &[x, y, ..] in &mesh.vertices {
+                    let point = Rect::from_corners(Vec2::new(x, y), Vec2::new(x, y));
+                    self.bounds = Some(match self.bounds {
+                        Some(bounds) => bounds.union(point),
+                        None => point,
+                    });
+                }
+            }
+            let category = mesh.category.as_deref().unwrap_or("(none)");
+            match self
+                .categories
+                .iter_mut()
+                .find(|(name, _)| name == category)
+            {
+                Some((_, count)) => *count += 1,
+                None => self.categories.push((category.to_owned(), 1)),
+            }
+        }
+    }
+
+    fn print(&self) {
+        println!("frames: {}", self.frames);
+        if let Some(first_time) = self.first_time {
+            println!("duration: {:.2}s", self.last_time - first_time);
+        }
+        if self.frames > 0 {
+            println!(
+                "meshes per frame: {:.1} average, {} max",
+                self.meshes as f32 / self.frames as f32,
+                self.max_meshes
+            );
+        }
+        println!("triangles: {}", self.triangles);
+        if let Some(bounds) = self.bounds {
+            println!(
+                "world bounds: {},{},{},{}",
+                bounds.min.x, bounds.min.y, bounds.max.x, bounds.max.y
+            );
+        }
+        for (category, count) in &self.categories {
+            println!("category {}: {} meshes", category, count);
+        }
+    }
+}
@@ -0,0 +1,72 @@
+use std::mem::replace;
+
+use bevy::render::render_resource::{BlendComponent, BlendFactor, BlendOperation, BlendState};
+
+use crate::{DebugDraw, DebugDrawDrawable};
+
+/// How a draw is combined with what is already on screen.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "recording", derive(serde::Serialize, serde::Deserialize))]
+pub enum DebugDrawBlendMode {
+    #[default]
+    Alpha,
+    Additive,
+    Multiply,
+    /// Inverts what is behind the draw, scaled by its alpha. The color itself is ignored.
+    Invert,
+}
+
+impl DebugDrawBlendMode {
+    pub const ALL: [DebugDrawBlendMode; 4] = [
+        DebugDrawBlendMode::Alpha,
+        DebugDrawBlendMode::Additive,
+        DebugDrawBlendMode::Multiply,
+        DebugDrawBlendMode::Invert,
+    ];
+
+    pub(crate) fn blend_state(&self) -> BlendState {
+        let color = match self {
+            DebugDrawBlendMode::Alpha => return BlendState::ALPHA_BLENDING,
+            DebugDrawBlendMode::Additive => BlendComponent {
+                src_factor: BlendFactor::SrcAlpha,
+                dst_factor: BlendFactor::One,
+                operation: BlendOperation::Add,
+            },
+            // The shader premultiplies the color, so this is `dst * lerp(1, src, src_alpha)`.
+            DebugDrawBlendMode::Multiply => BlendComponent {
+                src_factor: BlendFactor::Dst,
+                dst_factor: BlendFactor::OneMinusSrcAlpha,
+                operation: BlendOperation::Add,
+            },
+            // The shader outputs the alpha on every channel, so this is
+            // `lerp(dst, 1 - dst, src_alpha)`.
+            DebugDrawBlendMode::Invert => BlendComponent {
+                src_factor: BlendFactor::OneMinusDst,
+                dst_factor: BlendFactor::OneMinusSrc,
+                operation: BlendOperation::Add,
+            },
+        };
+        BlendState {
+            color,
+            alpha: BlendComponent::OVER,
+        }
+    }
+}
+
+impl DebugDraw {
+    /// Draws a single drawable with the given blend mode, regardless of the current blend mode.
+    pub fn draw_blended<T: DebugDrawDrawable>(&mut self, blend_mode: DebugDrawBlendMode, mesh: T) {
+        let previous_blend_mode = replace(&mut self.blend_mode, blend_mode);
+        self.draw(mesh);
+        self.blend_mode = previous_blend_mode;
+    }
+
+    /// Sets the blend mode used by every following draw, until it is changed again.
+    pub fn set_blend_mode(&mut self, blend_mode: DebugDrawBlendMode) {
+        self.blend_mode = blend_mode;
+    }
+
+    pub fn blend_mode(&self) -> DebugDrawBlendMode {
+        self.blend_mode
+    }
+}
@@ -0,0 +1,78 @@
+use bevy::prelude::*;
+
+use crate::{DebugDrawDrawable3d, DebugDrawMesh3d, DebugDrawVertex3d};
+
+#[derive(Clone, Copy, Debug)]
+pub struct DebugBox {
+    pub position: Vec3,
+    pub size: Vec3,
+    pub rotation: Quat,
+    pub color: Color,
+    /// Draws only the edges, as lines of the given `thickness`.
+    pub wireframe: bool,
+    pub thickness: f32,
+}
+
+impl Default for DebugBox {
+    fn default() -> Self {
+        Self {
+            position: Vec3::ZERO,
+            size: Vec3::ONE,
+            rotation: Quat::IDENTITY,
+            color: Color::BLACK,
+            wireframe: false,
+            thickness: 0.02,
+        }
+    }
+}
+
+impl DebugBox {
+    /// Corner `index` has its x, y and z on the positive side when bits 0, 1 and 2 are set.
+    fn corner(&self, index: u32) -> Vec3 {
+        let unit = Vec3::new(
+            if index & 1 == 0 { -0.5 } else { 0.5 },
+            if index & 2 == 0 { -0.5 } else { 0.5 },
+            if index & 4 == 0 { -0.5 } else { 0.5 },
+        );
+        self.position + self.rotation * (unit * self.size)
+    }
+}
+
+impl DebugDrawDrawable3d for DebugBox {
+    fn to_mesh_3d(&self) -> DebugDrawMesh3d {
+        let mut mesh = DebugDrawMesh3d::new();
+        if self.wireframe {
+            for from in 0..8 {
+                for axis in 0..3 {
+                    let to = from | (1 << axis);
+                    if to != from {
+                        mesh.add_segment(
+                            self.corner(from),
+                            self.corner(to),
+                            self.thickness,
+                            self.color,
+                            self.color,
+                        );
+                    }
+                }
+            }
+        } else {
+            for index in 0..8 {
+                mesh.vertices.push(DebugDrawVertex3d {
+                    position: self.corner(index),
+                    color: self.color,
+                });
+            }
+            for axis in 0..3 {
+                let u = 1 << ((axis + 1) % 3);
+                let v = 1 << ((axis + 2) % 3);
+                for side in [0, 1 << axis] {
+                    let quad = [side, side | u, side | u | v, side | v];
+                    mesh.indices
+                        .extend_from_slice(&[quad[0], quad[1], quad[2], quad[0], quad[2], quad[3]]);
+                }
+            }
+        }
+        mesh
+    }
+}
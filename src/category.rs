@@ -0,0 +1,206 @@
+use std::{any::type_name, borrow::Cow, mem::take};
+
+use bevy::prelude::*;
+
+use crate::{DebugDraw, DebugDrawDrawable, DebugDrawInstance, DebugDrawMesh};
+
+/// A label used to group draws, so they can be toggled together at runtime.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct DebugDrawCategory(Cow<'static, str>);
+
+impl DebugDrawCategory {
+    pub fn new(name: impl Into<Cow<'static, str>>) -> Self {
+        Self(name.into())
+    }
+
+    /// A category named after a type, for when a marker type is preferred over a string.
+    pub fn of<T: ?Sized + 'static>() -> Self {
+        Self(type_name::<T>().into())
+    }
+
+    pub fn name(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<&'static str> for DebugDrawCategory {
+    fn from(name: &'static str) -> Self {
+        Self::new(name)
+    }
+}
+
+impl From<String> for DebugDrawCategory {
+    fn from(name: String) -> Self {
+        Self::new(name)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DebugDrawCategorySettings {
+    pub enabled: bool,
+    /// Replaces the depth of every draw in the category.
+    pub depth: Option<f32>,
+    /// Multiplies the alpha of every draw in the category.
+    pub opacity: f32,
+    /// Multiplies the color of every draw in the category.
+    pub tint: Option<Color>,
+}
+
+impl Default for DebugDrawCategorySettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            depth: None,
+            opacity: 1.,
+            tint: None,
+        }
+    }
+}
+
+impl DebugDrawCategorySettings {
+    fn apply_color(&self, color: Color) -> Color {
+        let mut color = match self.tint {
+            Some(tint) => Color::rgba(
+                color.r() * tint.r(),
+                color.g() * tint.g(),
+                color.b() * tint.b(),
+                color.a() * tint.a(),
+            ),
+            None => color,
+        };
+        color.set_a(color.a() * self.opacity);
+        color
+    }
+
+    pub(crate) fn apply_to_mesh(&self, mesh: &mut DebugDrawMesh) {
+        if let Some(depth) = self.depth {
+            mesh.depth = depth;
+        }
+        if self.tint.is_some() || self.opacity != 1. {
+            for vertex in mesh.vertices.iter_mut() {
+                vertex.color = self.apply_color(vertex.color);
+            }
+        }
+    }
+
+    pub(crate) fn apply_to_instance(&self, instance: &mut DebugDrawInstance) {
+        if let Some(depth) = self.depth {
+            instance.depth = depth;
+        }
+        instance.start_color = self.apply_color(instance.start_color);
+        instance.end_color = self.apply_color(instance.end_color);
+    }
+}
+
+impl DebugDraw {
+    /// Draws a single drawable in the given category, regardless of the current category.
+    pub fn draw_in<T: DebugDrawDrawable>(
+        &mut self,
+        category: impl Into<DebugDrawCategory>,
+        mesh: T,
+    ) {
+        let previous_category = self.category.replace(category.into());
+        self.draw(mesh);
+        self.category = previous_category;
+    }
+
+    /// Sets the category used by every following draw, until it is changed again.
+    pub fn set_category(&mut self, category: impl Into<DebugDrawCategory>) {
+        self.category = Some(category.into());
+    }
+
+    /// Stops tagging the following draws with a category.
+    pub fn clear_category(&mut self) {
+        self.category = None;
+    }
+
+    pub fn category(&self) -> Option<&DebugDrawCategory> {
+        self.category.as_ref()
+    }
+
+    pub fn category_settings(
+        &self,
+        category: impl Into<DebugDrawCategory>,
+    ) -> DebugDrawCategorySettings {
+        self.categories
+            .get(&category.into())
+            .copied()
+            .unwrap_or_default()
+    }
+
+    pub fn category_settings_mut(
+        &mut self,
+        category: impl Into<DebugDrawCategory>,
+    ) -> &mut DebugDrawCategorySettings {
+        self.categories.entry(category.into()).or_default()
+    }
+
+    pub fn enable_category(&mut self, category: impl Into<DebugDrawCategory>) {
+        self.category_settings_mut(category).enabled = true;
+    }
+
+    pub fn disable_category(&mut self, category: impl Into<DebugDrawCategory>) {
+        self.category_settings_mut(category).enabled = false;
+    }
+
+    pub fn toggle_category(&mut self, category: impl Into<DebugDrawCategory>) {
+        let settings = self.category_settings_mut(category);
+        settings.enabled = !settings.enabled;
+    }
+
+    /// Hides every draw outside of the given category, including uncategorized ones.
+    pub fn solo_category(&mut self, category: impl Into<DebugDrawCategory>) {
+        self.solo = Some(category.into());
+    }
+
+    pub fn clear_solo(&mut self) {
+        self.solo = None;
+    }
+
+    pub fn solo(&self) -> Option<&DebugDrawCategory> {
+        self.solo.as_ref()
+    }
+
+    /// Whether draws in the given category, or uncategorized draws for `None`, are shown.
+    pub fn is_category_enabled(&self, category: Option<&DebugDrawCategory>) -> bool {
+        if let Some(solo) = &self.solo {
+            if category != Some(solo) {
+                return false;
+            }
+        }
+        match category.and_then(|category| self.categories.get(category)) {
+            Some(settings) => settings.enabled,
+            None => true,
+        }
+    }
+
+    /// Drops the calls and instances of disabled categories, and styles the others with the
+    /// current settings of their category. Retained draws are styled again every frame.
+    pub(crate) fn apply_category_settings(&mut self) {
+        let mut calls = take(&mut self.calls);
+        calls.retain_mut(|call| {
+            let category = call.category.as_ref();
+            if !self.is_category_enabled(category) {
+                return false;
+            }
+            if let Some(settings) = category.and_then(|category| self.categories.get(category)) {
+                settings.apply_to_mesh(&mut call.mesh);
+            }
+            true
+        });
+        self.calls = calls;
+
+        let mut instances = take(&mut self.instances);
+        instances.retain_mut(|(_, category, instance)| {
+            let category = category.as_ref();
+            if !self.is_category_enabled(category) {
+                return false;
+            }
+            if let Some(settings) = category.and_then(|category| self.categories.get(category)) {
+                settings.apply_to_instance(instance);
+            }
+            true
+        });
+        self.instances = instances;
+    }
+}
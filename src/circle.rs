@@ -2,7 +2,7 @@ use std::f32::consts::TAU;
 
 use bevy::prelude::*;
 
-use crate::{DebugDrawDrawable, DebugDrawMesh, DebugDrawVertex};
+use crate::{DebugDrawDrawable, DebugDrawInstance, DebugDrawMesh, DebugDrawVertex};
 
 #[derive(Clone, Copy, Debug)]
 pub struct DebugCircle {
@@ -36,6 +36,7 @@ impl DebugDrawDrawable for DebugCircle {
             vertices.push(DebugDrawVertex {
                 position: self.position + Vec2::from_angle(angle) * self.radius * 0.5,
                 color: self.color,
+                ..Default::default()
             });
             indices.push(self.segments as u32);
             indices.push(segment as u32);
@@ -44,11 +45,25 @@ impl DebugDrawDrawable for DebugCircle {
         vertices.push(DebugDrawVertex {
             position: self.position,
             color: self.color,
+            ..Default::default()
         });
         DebugDrawMesh {
             vertices,
             indices,
             depth: self.depth,
+            ..Default::default()
         }
     }
+
+    fn to_instance(&self) -> Option<DebugDrawInstance> {
+        Some(DebugDrawInstance {
+            center: self.position,
+            size: Vec2::splat(self.radius),
+            rotation: self.rotation,
+            segments: self.segments,
+            start_color: self.color,
+            end_color: self.color,
+            depth: self.depth,
+        })
+    }
 }
@@ -0,0 +1,90 @@
+use std::f32::consts::PI;
+
+use bevy::prelude::*;
+
+use crate::{ring_point, DebugDrawDrawable3d, DebugDrawMesh3d, DebugDrawVertex3d};
+
+#[derive(Clone, Copy, Debug)]
+pub struct DebugCylinder {
+    pub from: Vec3,
+    pub to: Vec3,
+    pub radius: f32,
+    pub segments: u8,
+    pub color: Color,
+    /// Draws both caps and four side edges, as lines of the given `thickness`.
+    pub wireframe: bool,
+    pub thickness: f32,
+}
+
+impl Default for DebugCylinder {
+    fn default() -> Self {
+        Self {
+            from: Vec3::ZERO,
+            to: Vec3::Y,
+            radius: 0.5,
+            segments: 32,
+            color: Color::BLACK,
+            wireframe: false,
+            thickness: 0.02,
+        }
+    }
+}
+
+impl DebugDrawDrawable3d for DebugCylinder {
+    fn to_mesh_3d(&self) -> DebugDrawMesh3d {
+        let mut mesh = DebugDrawMesh3d::new();
+        if self.from == self.to {
+            return mesh;
+        }
+        let segments = self.segments.max(3);
+        let (u, v) = (self.to - self.from).normalize().any_orthonormal_pair();
+        if self.wireframe {
+            for center in [self.from, self.to] {
+                mesh.add_ring(
+                    center,
+                    u,
+                    v,
+                    self.radius,
+                    segments,
+                    self.thickness,
+                    self.color,
+                );
+            }
+            for quarter in 0..4 {
+                let offset = (u * (quarter as f32 * 0.5 * PI).cos()
+                    + v * (quarter as f32 * 0.5 * PI).sin())
+                    * self.radius;
+                mesh.add_segment(
+                    self.from + offset,
+                    self.to + offset,
+                    self.thickness,
+                    self.color,
+                    self.color,
+                );
+            }
+        } else {
+            for center in [self.from, self.to] {
+                mesh.vertices.push(DebugDrawVertex3d {
+                    position: center,
+                    color: self.color,
+                });
+                for segment in 0..segments {
+                    mesh.vertices.push(DebugDrawVertex3d {
+                        position: ring_point(center, u, v, self.radius, segment, segments),
+                        color: self.color,
+                    });
+                }
+            }
+            let segments = segments as u32;
+            let top = segments + 1;
+            for segment in 0..segments {
+                let next_segment = (segment + 1) % segments;
+                let (a, b) = (1 + segment, 1 + next_segment);
+                let (c, d) = (top + 1 + segment, top + 1 + next_segment);
+                mesh.indices
+                    .extend_from_slice(&[0, a, b, top, c, d, a, c, b, b, c, d]);
+            }
+        }
+        mesh
+    }
+}
@@ -0,0 +1,58 @@
+use crate::DebugDraw;
+
+/// How the depth of a draw maps to the z of the mesh it is rendered in. Draws that map to the
+/// same z share a mesh, and are ordered by depth inside it.
+#[derive(Debug, Clone, Copy)]
+pub enum DebugDrawDepthMapping {
+    /// Every draw is rendered at the same z, and depth only orders draws between themselves.
+    Fixed(f32),
+    /// `z = depth * scale + offset`, rounded to a multiple of `step` so that draws at similar
+    /// depths share a mesh. A `step` of zero disables rounding.
+    Linear { scale: f32, offset: f32, step: f32 },
+    /// Like `Linear`, but with an arbitrary function.
+    Custom { map: fn(f32) -> f32, step: f32 },
+}
+
+impl Default for DebugDrawDepthMapping {
+    fn default() -> Self {
+        Self::Fixed(1.)
+    }
+}
+
+impl DebugDrawDepthMapping {
+    /// Lets depth compose with sprite z-order directly, one mesh per integer depth.
+    pub fn z() -> Self {
+        Self::Linear {
+            scale: 1.,
+            offset: 0.,
+            step: 1.,
+        }
+    }
+
+    pub fn z_for(&self, depth: f32) -> f32 {
+        let (z, step) = match *self {
+            DebugDrawDepthMapping::Fixed(z) => return z,
+            DebugDrawDepthMapping::Linear {
+                scale,
+                offset,
+                step,
+            } => (depth * scale + offset, step),
+            DebugDrawDepthMapping::Custom { map, step } => (map(depth), step),
+        };
+        if step > 0. {
+            (z / step).round() * step
+        } else {
+            z
+        }
+    }
+}
+
+impl DebugDraw {
+    pub fn set_depth_mapping(&mut self, depth_mapping: DebugDrawDepthMapping) {
+        self.depth_mapping = depth_mapping;
+    }
+
+    pub fn depth_mapping(&self) -> DebugDrawDepthMapping {
+        self.depth_mapping
+    }
+}
@@ -0,0 +1,288 @@
+use std::{
+    collections::{hash_map::DefaultHasher, HashMap},
+    hash::{Hash, Hasher},
+    time::Duration,
+};
+
+use bevy::{
+    diagnostic::{Diagnostic, DiagnosticId, Diagnostics},
+    prelude::*,
+    utils::Uuid,
+};
+
+use crate::{
+    debug_3d_renderer, debug_headless_renderer, debug_renderer, DebugDraw, DebugDraw3d,
+    DebugDrawCategory, DebugDrawSystem,
+};
+
+/// Registers diagnostics for the draws of every frame, shown by `LogDiagnosticsPlugin`. Each
+/// category also gets draw, instance, vertex and index counts, named after the category. The
+/// draws of [`DebugDraw3d`] are reported separately.
+pub struct DebugDrawDiagnosticsPlugin;
+
+impl DebugDrawDiagnosticsPlugin {
+    pub const DRAWS: DiagnosticId = DiagnosticId::from_u128(0x8b1f0c52_6a3e_4d71_9f0e_2c4b7d5a1e01);
+    pub const VERTICES: DiagnosticId =
+        DiagnosticId::from_u128(0x8b1f0c52_6a3e_4d71_9f0e_2c4b7d5a1e02);
+    pub const INDICES: DiagnosticId =
+        DiagnosticId::from_u128(0x8b1f0c52_6a3e_4d71_9f0e_2c4b7d5a1e03);
+    /// Milliseconds spent turning drawables into meshes.
+    pub const TESSELLATION_TIME: DiagnosticId =
+        DiagnosticId::from_u128(0x8b1f0c52_6a3e_4d71_9f0e_2c4b7d5a1e04);
+    /// Milliseconds spent batching meshes and writing them to mesh assets.
+    pub const UPLOAD_TIME: DiagnosticId =
+        DiagnosticId::from_u128(0x8b1f0c52_6a3e_4d71_9f0e_2c4b7d5a1e05);
+    /// Instanced draws, which have no vertices or indices on the CPU.
+    pub const INSTANCES: DiagnosticId =
+        DiagnosticId::from_u128(0x8b1f0c52_6a3e_4d71_9f0e_2c4b7d5a1e06);
+    pub const DRAWS_3D: DiagnosticId =
+        DiagnosticId::from_u128(0x8b1f0c52_6a3e_4d71_9f0e_2c4b7d5a1e07);
+    pub const VERTICES_3D: DiagnosticId =
+        DiagnosticId::from_u128(0x8b1f0c52_6a3e_4d71_9f0e_2c4b7d5a1e08);
+    pub const INDICES_3D: DiagnosticId =
+        DiagnosticId::from_u128(0x8b1f0c52_6a3e_4d71_9f0e_2c4b7d5a1e09);
+
+    /// The diagnostic of a category, for one of [`Self::DRAWS`], [`Self::INSTANCES`],
+    /// [`Self::VERTICES`] or [`Self::INDICES`].
+    pub fn category_diagnostic(
+        category: &DebugDrawCategory,
+        diagnostic: DiagnosticId,
+    ) -> DiagnosticId {
+        let mut hasher = DefaultHasher::new();
+        category.hash(&mut hasher);
+        diagnostic.hash(&mut hasher);
+        let hash = hasher.finish();
+        DiagnosticId(Uuid::from_u64_pair(hash, !hash))
+    }
+}
+
+impl Plugin for DebugDrawDiagnosticsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<DebugDraw>()
+            .init_resource::<Diagnostics>()
+            .add_startup_system(setup_debug_draw_diagnostics)
+            .add_system(
+                debug_draw_diagnostics
+                    .in_set(DebugDrawSystem)
+                    .after(debug_renderer)
+                    .after(debug_headless_renderer)
+                    .after(debug_3d_renderer),
+            );
+    }
+}
+
+/// Statistics of the draws rendered by the last frame. Instanced draws are counted separately
+/// from the meshes.
+#[derive(Clone, Debug, Default)]
+pub struct DebugDrawStats {
+    pub draws: usize,
+    pub instances: usize,
+    pub vertices: usize,
+    pub indices: usize,
+    pub tessellation_time: Duration,
+    pub upload_time: Duration,
+    /// Counts of every category drawn so far. Categories not drawn this frame count zero.
+    pub categories: HashMap<DebugDrawCategory, DebugDrawCategoryStats>,
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct DebugDrawCategoryStats {
+    pub draws: usize,
+    pub instances: usize,
+    pub vertices: usize,
+    pub indices: usize,
+}
+
+impl DebugDraw {
+    pub fn stats(&self) -> &DebugDrawStats {
+        &self.stats
+    }
+
+    /// Counts the calls of the frame, before they become the rendered calls.
+    pub(crate) fn update_stats(&mut self, upload_time: Duration) {
+        let stats = &mut self.stats;
+        stats.draws = self.calls.len();
+        stats.instances = self.instances.len();
+        stats.vertices = 0;
+        stats.indices = 0;
+        stats.tessellation_time = std::mem::take(&mut self.tessellation_time);
+        stats.upload_time = upload_time;
+        for category_stats in stats.categories.values_mut() {
+            *category_stats = DebugDrawCategoryStats::default();
+        }
+        for call in self.calls.iter() {
+            stats.vertices += call.mesh.vertices.len();
+            stats.indices += call.mesh.indices.len();
+            if let Some(category) = &call.category {
+                let category_stats = category_stats(&mut stats.categories, category);
+                category_stats.draws += 1;
+                category_stats.vertices += call.mesh.vertices.len();
+                category_stats.indices += call.mesh.indices.len();
+            }
+        }
+        for (_, category, _) in self.instances.iter() {
+            if let Some(category) = category {
+                category_stats(&mut stats.categories, category).instances += 1;
+            }
+        }
+    }
+}
+
+fn category_stats<'a>(
+    categories: &'a mut HashMap<DebugDrawCategory, DebugDrawCategoryStats>,
+    category: &DebugDrawCategory,
+) -> &'a mut DebugDrawCategoryStats {
+    // Looked up first, so the category is only cloned the first time it's drawn.
+    if !categories.contains_key(category) {
+        categories.insert(category.clone(), DebugDrawCategoryStats::default());
+    }
+    categories.get_mut(category).unwrap()
+}
+
+/// Statistics of the [`DebugDraw3d`] draws rendered by the last frame.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct DebugDraw3dStats {
+    pub draws: usize,
+    pub vertices: usize,
+    pub indices: usize,
+}
+
+impl DebugDraw3d {
+    pub fn stats(&self) -> &DebugDraw3dStats {
+        &self.stats
+    }
+
+    /// Counts the calls of the frame, including the latest fixed tick draws.
+    pub(crate) fn update_stats(&mut self) {
+        let mut stats = DebugDraw3dStats::default();
+        for call in self.calls.iter().chain(self.fixed_calls.iter()) {
+            stats.draws += 1;
+            stats.vertices += call.mesh.vertices.len();
+            stats.indices += call.mesh.indices.len();
+        }
+        self.stats = stats;
+    }
+}
+
+fn setup_debug_draw_diagnostics(mut diagnostics: ResMut<Diagnostics>) {
+    diagnostics.add(Diagnostic::new(
+        DebugDrawDiagnosticsPlugin::DRAWS,
+        "debug_draw/draws",
+        20,
+    ));
+    diagnostics.add(Diagnostic::new(
+        DebugDrawDiagnosticsPlugin::INSTANCES,
+        "debug_draw/instances",
+        20,
+    ));
+    diagnostics.add(Diagnostic::new(
+        DebugDrawDiagnosticsPlugin::VERTICES,
+        "debug_draw/vertices",
+        20,
+    ));
+    diagnostics.add(Diagnostic::new(
+        DebugDrawDiagnosticsPlugin::INDICES,
+        "debug_draw/indices",
+        20,
+    ));
+    diagnostics.add(
+        Diagnostic::new(
+            DebugDrawDiagnosticsPlugin::TESSELLATION_TIME,
+            "debug_draw/tessellation",
+            20,
+        )
+        .with_suffix("ms"),
+    );
+    diagnostics.add(
+        Diagnostic::new(
+            DebugDrawDiagnosticsPlugin::UPLOAD_TIME,
+            "debug_draw/upload",
+            20,
+        )
+        .with_suffix("ms"),
+    );
+    diagnostics.add(Diagnostic::new(
+        DebugDrawDiagnosticsPlugin::DRAWS_3D,
+        "debug_draw/3d/draws",
+        20,
+    ));
+    diagnostics.add(Diagnostic::new(
+        DebugDrawDiagnosticsPlugin::VERTICES_3D,
+        "debug_draw/3d/vertices",
+        20,
+    ));
+    diagnostics.add(Diagnostic::new(
+        DebugDrawDiagnosticsPlugin::INDICES_3D,
+        "debug_draw/3d/indices",
+        20,
+    ));
+}
+
+fn debug_draw_diagnostics(
+    debug_draw: Res<DebugDraw>,
+    debug_draw_3d: Option<Res<DebugDraw3d>>,
+    mut diagnostics: ResMut<Diagnostics>,
+) {
+    let stats = debug_draw.stats();
+    diagnostics.add_measurement(DebugDrawDiagnosticsPlugin::DRAWS, || stats.draws as f64);
+    diagnostics.add_measurement(DebugDrawDiagnosticsPlugin::INSTANCES, || {
+        stats.instances as f64
+    });
+    diagnostics.add_measurement(DebugDrawDiagnosticsPlugin::VERTICES, || {
+        stats.vertices as f64
+    });
+    diagnostics.add_measurement(DebugDrawDiagnosticsPlugin::INDICES, || stats.indices as f64);
+    diagnostics.add_measurement(DebugDrawDiagnosticsPlugin::TESSELLATION_TIME, || {
+        stats.tessellation_time.as_secs_f64() * 1000.
+    });
+    diagnostics.add_measurement(DebugDrawDiagnosticsPlugin::UPLOAD_TIME, || {
+        stats.upload_time.as_secs_f64() * 1000.
+    });
+    if let Some(debug_draw_3d) = debug_draw_3d {
+        let stats_3d = debug_draw_3d.stats();
+        diagnostics.add_measurement(DebugDrawDiagnosticsPlugin::DRAWS_3D, || {
+            stats_3d.draws as f64
+        });
+        diagnostics.add_measurement(DebugDrawDiagnosticsPlugin::VERTICES_3D, || {
+            stats_3d.vertices as f64
+        });
+        diagnostics.add_measurement(DebugDrawDiagnosticsPlugin::INDICES_3D, || {
+            stats_3d.indices as f64
+        });
+    }
+
+    for (category, category_stats) in stats.categories.iter() {
+        for (diagnostic, name, value) in [
+            (
+                DebugDrawDiagnosticsPlugin::DRAWS,
+                "draws",
+                category_stats.draws,
+            ),
+            (
+                DebugDrawDiagnosticsPlugin::INSTANCES,
+                "instances",
+                category_stats.instances,
+            ),
+            (
+                DebugDrawDiagnosticsPlugin::VERTICES,
+                "vertices",
+                category_stats.vertices,
+            ),
+            (
+                DebugDrawDiagnosticsPlugin::INDICES,
+                "indices",
+                category_stats.indices,
+            ),
+        ] {
+            let id = DebugDrawDiagnosticsPlugin::category_diagnostic(category, diagnostic);
+            if diagnostics.get(id).is_none() {
+                diagnostics.add(Diagnostic::new(
+                    id,
+                    format!("debug_draw/{}/{}", category.name(), name),
+                    20,
+                ));
+            }
+            diagnostics.add_measurement(id, || value as f64);
+        }
+    }
+}
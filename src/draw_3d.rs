@@ -0,0 +1,395 @@
+use std::{f32::consts::TAU, mem::take};
+
+use bevy::{
+    pbr::{MaterialPipeline, MaterialPipelineKey, NotShadowCaster},
+    prelude::*,
+    reflect::TypeUuid,
+    render::{
+        mesh::{Indices, MeshVertexBufferLayout, VertexAttributeValues},
+        render_resource::{
+            AsBindGroup, CompareFunction, PrimitiveTopology, RenderPipelineDescriptor,
+            SpecializedMeshPipelineError,
+        },
+        view::NoFrustumCulling,
+    },
+};
+
+use crate::{configure_debug_draw_system, reuse_attribute, DebugDraw3dStats, DebugDrawSystem};
+
+pub struct DebugDraw3dPlugin;
+
+impl Plugin for DebugDraw3dPlugin {
+    fn build(&self, app: &mut App) {
+        configure_debug_draw_system(app);
+        app.add_plugin(MaterialPlugin::<DebugDraw3dMaterial>::default())
+            .init_resource::<DebugDraw3d>()
+            .add_startup_system(debug_3d_setup)
+            .add_system(debug_3d_renderer.in_set(DebugDrawSystem));
+    }
+}
+
+/// The 3D counterpart of [`DebugDraw`](crate::DebugDraw). Draws are depth tested against the
+/// scene unless depth testing is turned off.
+#[derive(Resource)]
+pub struct DebugDraw3d {
+    pub(crate) calls: Vec<DebugDraw3dCall>,
+    depth_test: bool,
+    pub(crate) fixed_tick: bool,
+    pub(crate) fixed_calls: Vec<DebugDraw3dCall>,
+    pub(crate) stats: DebugDraw3dStats,
+}
+
+impl Default for DebugDraw3d {
+    fn default() -> Self {
+        Self {
+            calls: vec![],
+            depth_test: true,
+            fixed_tick: false,
+            fixed_calls: vec![],
+            stats: DebugDraw3dStats::default(),
+        }
+    }
+}
+
+impl DebugDraw3d {
+    pub fn draw<T: DebugDrawDrawable3d>(&mut self, mesh: T) {
+        self.push(DebugDraw3dCall {
+            mesh: mesh.to_mesh_3d(),
+            depth_test: self.depth_test,
+        });
+    }
+
+    /// Draws a single drawable on top of the scene, regardless of the current depth test setting.
+    pub fn draw_on_top<T: DebugDrawDrawable3d>(&mut self, mesh: T) {
+        self.push(DebugDraw3dCall {
+            mesh: mesh.to_mesh_3d(),
+            depth_test: false,
+        });
+    }
+
+    /// Keeps draws issued during a fixed tick until the next tick, like
+    /// [`DebugDraw`](crate::DebugDraw).
+    fn push(&mut self, call: DebugDraw3dCall) {
+        if self.fixed_tick {
+            self.fixed_calls.push(call);
+        } else {
+            self.calls.push(call);
+        }
+    }
+
+    /// Sets whether every following draw is hidden behind scene geometry.
+    pub fn set_depth_test(&mut self, depth_test: bool) {
+        self.depth_test = depth_test;
+    }
+
+    pub fn depth_test(&self) -> bool {
+        self.depth_test
+    }
+}
+
+pub(crate) struct DebugDraw3dCall {
+    pub(crate) mesh: DebugDrawMesh3d,
+    depth_test: bool,
+}
+
+pub trait DebugDrawDrawable3d {
+    fn to_mesh_3d(&self) -> DebugDrawMesh3d;
+}
+
+#[derive(Default, Debug, Clone)]
+pub struct DebugDrawMesh3d {
+    pub vertices: Vec<DebugDrawVertex3d>,
+    pub indices: Vec<u32>,
+    /// When set, the x and y of every vertex are offsets in the camera plane from this world
+    /// position, so the mesh always faces the camera.
+    pub billboard: Option<Vec3>,
+}
+
+impl DebugDrawDrawable3d for DebugDrawMesh3d {
+    fn to_mesh_3d(&self) -> DebugDrawMesh3d {
+        self.clone()
+    }
+}
+
+impl DebugDrawMesh3d {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn merge_with(&mut self, other: &DebugDrawMesh3d) {
+        let base_index = self.vertices.len() as u32;
+        self.vertices.extend(other.vertices.iter());
+        self.indices.reserve(other.indices.len());
+        for index in other.indices.iter() {
+            self.indices.push(base_index + *index);
+        }
+    }
+
+    /// Adds a square prism between two points, which is how every thick 3D line is built.
+    pub fn add_segment(
+        &mut self,
+        from: Vec3,
+        to: Vec3,
+        thickness: f32,
+        from_color: Color,
+        to_color: Color,
+    ) {
+        if from == to {
+            return;
+        }
+        let (side, up) = (to - from).normalize().any_orthonormal_pair();
+        let side = side * thickness * 0.5;
+        let up = up * thickness * 0.5;
+        let base_index = self.vertices.len() as u32;
+        for corner in [side + up, side - up, -side - up, -side + up] {
+            self.vertices.push(DebugDrawVertex3d {
+                position: from + corner,
+                color: from_color,
+            });
+            self.vertices.push(DebugDrawVertex3d {
+                position: to + corner,
+                color: to_color,
+            });
+        }
+        for face in 0..4 {
+            let a = base_index + face * 2;
+            let b = base_index + ((face + 1) % 4) * 2;
+            self.indices
+                .extend_from_slice(&[a, a + 1, b, b, a + 1, b + 1]);
+        }
+    }
+}
+
+impl DebugDrawMesh3d {
+    /// Adds a circle of segments around `center`, in the plane spanned by the unit vectors `u`
+    /// and `v`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn add_ring(
+        &mut self,
+        center: Vec3,
+        u: Vec3,
+        v: Vec3,
+        radius: f32,
+        segments: u8,
+        thickness: f32,
+        color: Color,
+    ) {
+        for segment in 0..segments {
+            let from = ring_point(center, u, v, radius, segment, segments);
+            let to = ring_point(center, u, v, radius, segment + 1, segments);
+            self.add_segment(from, to, thickness, color, color);
+        }
+    }
+}
+
+pub(crate) fn ring_point(
+    center: Vec3,
+    u: Vec3,
+    v: Vec3,
+    radius: f32,
+    segment: u8,
+    segments: u8,
+) -> Vec3 {
+    let angle = segment as f32 / segments as f32 * TAU;
+    center + (u * angle.cos() + v * angle.sin()) * radius
+}
+
+#[derive(Default, Debug, Clone, Copy)]
+pub struct DebugDrawVertex3d {
+    pub position: Vec3,
+    pub color: Color,
+}
+
+#[derive(AsBindGroup, TypeUuid, Debug, Clone)]
+#[uuid = "2a5f8d0c-6c5e-4d8e-9a63-5e7f1b2d3c41"]
+#[bind_group_data(DebugDraw3dMaterialKey)]
+struct DebugDraw3dMaterial {
+    depth_test: bool,
+}
+
+#[derive(Clone, Copy, Hash, PartialEq, Eq)]
+struct DebugDraw3dMaterialKey {
+    depth_test: bool,
+}
+
+impl From<&DebugDraw3dMaterial> for DebugDraw3dMaterialKey {
+    fn from(material: &DebugDraw3dMaterial) -> Self {
+        Self {
+            depth_test: material.depth_test,
+        }
+    }
+}
+
+impl Material for DebugDraw3dMaterial {
+    fn alpha_mode(&self) -> AlphaMode {
+        AlphaMode::Blend
+    }
+
+    fn specialize(
+        _pipeline: &MaterialPipeline<Self>,
+        descriptor: &mut RenderPipelineDescriptor,
+        _layout: &MeshVertexBufferLayout,
+        key: MaterialPipelineKey<Self>,
+    ) -> Result<(), SpecializedMeshPipelineError> {
+        descriptor.primitive.cull_mode = None;
+        if !key.bind_group_data.depth_test {
+            if let Some(depth_stencil) = descriptor.depth_stencil.as_mut() {
+                depth_stencil.depth_compare = CompareFunction::Always;
+                depth_stencil.depth_write_enabled = false;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Component)]
+pub(crate) struct DebugDraw3dObject {
+    depth_test: bool,
+}
+
+#[derive(Resource)]
+pub(crate) struct DebugDraw3dMaterials {
+    depth_tested: Handle<DebugDraw3dMaterial>,
+    on_top: Handle<DebugDraw3dMaterial>,
+}
+
+fn debug_3d_setup(mut commands: Commands, mut materials: ResMut<Assets<DebugDraw3dMaterial>>) {
+    commands.insert_resource(DebugDraw3dMaterials {
+        depth_tested: materials.add(DebugDraw3dMaterial { depth_test: true }),
+        on_top: materials.add(DebugDraw3dMaterial { depth_test: false }),
+    });
+}
+
+pub(crate) fn debug_3d_renderer(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut debug_draw_3d: ResMut<DebugDraw3d>,
+    debug_materials: Res<DebugDraw3dMaterials>,
+    mut debug_query: Query<(&DebugDraw3dObject, &Handle<Mesh>, &mut Visibility)>,
+    camera_query: Query<(&Camera, &GlobalTransform), With<Camera3d>>,
+) {
+    let camera_transform = camera_query
+        .iter()
+        .find(|(camera, _)| camera.is_active)
+        .map(|(_, camera_transform)| *camera_transform)
+        .unwrap_or_default();
+
+    debug_draw_3d.update_stats();
+    let mut depth_tested = vec![];
+    let mut on_top = vec![];
+    for call in debug_draw_3d
+        .calls
+        .iter()
+        .chain(debug_draw_3d.fixed_calls.iter())
+    {
+        if call.depth_test {
+            depth_tested.push(&call.mesh);
+        } else {
+            on_top.push(&call.mesh);
+        }
+    }
+
+    let mut spawn_depth_tested = !depth_tested.is_empty();
+    let mut spawn_on_top = !on_top.is_empty();
+    for (debug_object, debug_mesh_handle, mut debug_visibility) in debug_query.iter_mut() {
+        let batch = if debug_object.depth_test {
+            spawn_depth_tested = false;
+            &depth_tested
+        } else {
+            spawn_on_top = false;
+            &on_top
+        };
+        if batch.is_empty() {
+            *debug_visibility = Visibility::Hidden;
+        } else {
+            if let Some(mesh) = meshes.get_mut(debug_mesh_handle) {
+                write_mesh_3d(mesh, batch, &camera_transform);
+            }
+            *debug_visibility = Visibility::Inherited;
+        }
+    }
+
+    for (depth_test, batch, spawn) in [
+        (true, &depth_tested, spawn_depth_tested),
+        (false, &on_top, spawn_on_top),
+    ] {
+        if !spawn {
+            continue;
+        }
+        let mut mesh = Mesh::new(PrimitiveTopology::TriangleList);
+        write_mesh_3d(&mut mesh, batch, &camera_transform);
+        commands
+            .spawn(MaterialMeshBundle {
+                mesh: meshes.add(mesh),
+                material: if depth_test {
+                    debug_materials.depth_tested.clone()
+                } else {
+                    debug_materials.on_top.clone()
+                },
+                ..Default::default()
+            })
+            .insert((
+                DebugDraw3dObject { depth_test },
+                NoFrustumCulling,
+                NotShadowCaster,
+            ));
+    }
+
+    debug_draw_3d.calls.clear();
+}
+
+fn write_mesh_3d(
+    mesh: &mut Mesh,
+    debug_render_meshes: &[&DebugDrawMesh3d],
+    camera_transform: &GlobalTransform,
+) {
+    let mut positions = reuse_attribute(mesh, Mesh::ATTRIBUTE_POSITION, |values| match values {
+        VertexAttributeValues::Float32x3(values) => Some(values),
+        _ => None,
+    });
+    let mut normals = reuse_attribute(mesh, Mesh::ATTRIBUTE_NORMAL, |values| match values {
+        VertexAttributeValues::Float32x3(values) => Some(values),
+        _ => None,
+    });
+    let mut colors = reuse_attribute(mesh, Mesh::ATTRIBUTE_COLOR, |values| match values {
+        VertexAttributeValues::Float32x4(values) => Some(values),
+        _ => None,
+    });
+    let mut indices = match mesh.indices_mut() {
+        Some(Indices::U32(indices)) => take(indices),
+        _ => vec![],
+    };
+    indices.clear();
+
+    let camera_right = camera_transform.right();
+    let camera_up = camera_transform.up();
+    for debug_render_mesh in debug_render_meshes.iter() {
+        let base_index = positions.len() as u32;
+        for vertex in debug_render_mesh.vertices.iter() {
+            let position = if let Some(billboard) = debug_render_mesh.billboard {
+                billboard + camera_right * vertex.position.x + camera_up * vertex.position.y
+            } else {
+                vertex.position
+            };
+            positions.push(position.into());
+            normals.push([0., 0., 0.]);
+            colors.push([
+                vertex.color.r(),
+                vertex.color.g(),
+                vertex.color.b(),
+                vertex.color.a(),
+            ]);
+        }
+        indices.extend(
+            debug_render_mesh
+                .indices
+                .iter()
+                .map(|index| base_index + *index),
+        );
+    }
+
+    mesh.set_indices(Some(Indices::U32(indices)));
+    mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, positions);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, normals);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_COLOR, colors);
+}
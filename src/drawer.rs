@@ -0,0 +1,343 @@
+use std::{
+    mem::take,
+    ops::{Deref, DerefMut},
+    sync::{Arc, Mutex, MutexGuard},
+};
+
+use bevy::ecs::{
+    component::ComponentId,
+    system::{SystemMeta, SystemParam},
+    world::World,
+};
+use bevy::{math::Affine2, prelude::*};
+
+use crate::{
+    DebugDraw, DebugDrawAnchor, DebugDrawBlendMode, DebugDrawCall, DebugDrawCategory,
+    DebugDrawDrawable, DebugDrawDuration, DebugDrawHandle, DebugDrawSender, DebugDrawSpace,
+    DebugDrawTarget, DebugDrawTransform,
+};
+
+/// Submits draws like [`DebugDraw`], into a buffer of its own, so systems using it can run in
+/// parallel. Buffers are merged into [`DebugDraw`] before rendering, in the order the systems were
+/// added.
+///
+/// The current target, space, category, blend mode and transform stack belong to the system.
+/// Everything else, such as category toggles, is read from [`DebugDraw`] and changed through it.
+pub struct DebugDrawer<'w, 's> {
+    debug_draw: Res<'w, DebugDraw>,
+    buffer: MutexGuard<'s, DebugDrawerBuffer>,
+}
+
+impl<'w, 's> DebugDrawer<'w, 's> {
+    /// The main [`DebugDraw`], to read its settings.
+    pub fn debug_draw(&self) -> &DebugDraw {
+        &self.debug_draw
+    }
+
+    pub fn draw<T: DebugDrawDrawable>(&mut self, mesh: T) {
+        self.buffer.debug_draw.draw(mesh);
+    }
+
+    /// Draws a single drawable on the given target, regardless of the current target.
+    pub fn draw_on<T: DebugDrawDrawable>(&mut self, target: impl Into<DebugDrawTarget>, mesh: T) {
+        self.buffer.debug_draw.draw_on(target, mesh);
+    }
+
+    /// Sets the target used by every following draw of the system, until it is changed again.
+    pub fn set_target(&mut self, target: impl Into<DebugDrawTarget>) {
+        self.buffer.debug_draw.set_target(target);
+    }
+
+    pub fn target(&self) -> DebugDrawTarget {
+        self.buffer.debug_draw.target()
+    }
+
+    /// Draws a single drawable in screen space, in logical pixels relative to the anchor.
+    pub fn draw_screen<T: DebugDrawDrawable>(&mut self, anchor: DebugDrawAnchor, mesh: T) {
+        self.buffer.debug_draw.draw_screen(anchor, mesh);
+    }
+
+    /// Sets the space used by every following draw of the system, until it is changed again.
+    pub fn set_space(&mut self, space: DebugDrawSpace) {
+        self.buffer.debug_draw.set_space(space);
+    }
+
+    pub fn space(&self) -> DebugDrawSpace {
+        self.buffer.debug_draw.space()
+    }
+
+    /// Draws a single drawable in the given category, regardless of the current category.
+    pub fn draw_in<T: DebugDrawDrawable>(
+        &mut self,
+        category: impl Into<DebugDrawCategory>,
+        mesh: T,
+    ) {
+        self.buffer.debug_draw.draw_in(category, mesh);
+    }
+
+    /// Sets the category used by every following draw of the system, until it is changed again.
+    pub fn set_category(&mut self, category: impl Into<DebugDrawCategory>) {
+        self.buffer.debug_draw.set_category(category);
+    }
+
+    pub fn clear_category(&mut self) {
+        self.buffer.debug_draw.clear_category();
+    }
+
+    pub fn category(&self) -> Option<&DebugDrawCategory> {
+        self.buffer.debug_draw.category()
+    }
+
+    /// Draws a single drawable with the given blend mode, regardless of the current blend mode.
+    pub fn draw_blended<T: DebugDrawDrawable>(&mut self, blend_mode: DebugDrawBlendMode, mesh: T) {
+        self.buffer.debug_draw.draw_blended(blend_mode, mesh);
+    }
+
+    /// Sets the blend mode used by every following draw of the system, until it is changed again.
+    pub fn set_blend_mode(&mut self, blend_mode: DebugDrawBlendMode) {
+        self.buffer.debug_draw.set_blend_mode(blend_mode);
+    }
+
+    pub fn blend_mode(&self) -> DebugDrawBlendMode {
+        self.buffer.debug_draw.blend_mode()
+    }
+
+    /// Pushes a transform applied to every following draw, on top of the current one, until it is
+    /// popped.
+    pub fn push_transform(&mut self, transform: impl Into<DebugDrawTransform>) {
+        self.buffer.debug_draw.push_transform(transform);
+    }
+
+    pub fn pop_transform(&mut self) {
+        self.buffer.debug_draw.pop_transform();
+    }
+
+    /// Pushes the transform of an entity. Returns `false` if it doesn't have a [`GlobalTransform`].
+    pub fn push_entity_transform(
+        &mut self,
+        entity: Entity,
+        transform_query: &Query<&GlobalTransform>,
+    ) -> bool {
+        self.buffer
+            .debug_draw
+            .push_entity_transform(entity, transform_query)
+    }
+
+    /// Pushes a transform that is popped when the returned guard is dropped.
+    pub fn scoped_transform(
+        &mut self,
+        transform: impl Into<DebugDrawTransform>,
+    ) -> DebugDrawerTransformGuard<'_, 'w, 's> {
+        self.push_transform(transform);
+        DebugDrawerTransformGuard { debug_drawer: self }
+    }
+
+    /// The combination of every transform on the stack.
+    pub fn current_transform(&self) -> Affine2 {
+        self.buffer.debug_draw.current_transform()
+    }
+
+    /// Keeps drawing a drawable for the given duration.
+    pub fn draw_for<T: DebugDrawDrawable>(&mut self, duration: DebugDrawDuration, mesh: T) {
+        self.buffer.debug_draw.draw_for(duration, mesh);
+    }
+
+    /// Keeps drawing a drawable for the given duration, fading it out until it disappears.
+    pub fn draw_fading<T: DebugDrawDrawable>(&mut self, duration: DebugDrawDuration, mesh: T) {
+        self.buffer.debug_draw.draw_fading(duration, mesh);
+    }
+
+    /// Keeps drawing a drawable every frame until it is removed, through a drawer or
+    /// [`DebugDraw`].
+    pub fn draw_persistent<T: DebugDrawDrawable>(&mut self, mesh: T) -> DebugDrawHandle {
+        self.buffer.debug_draw.draw_persistent(mesh)
+    }
+
+    /// Replaces the drawable of a persistent draw, once the buffer is merged. Returns `false` if it
+    /// was already removed.
+    pub fn update_persistent<T: DebugDrawDrawable>(
+        &mut self,
+        handle: DebugDrawHandle,
+        mesh: T,
+    ) -> bool {
+        let buffer = &mut *self.buffer;
+        if buffer.debug_draw.persistent.contains_key(&handle) {
+            return buffer.debug_draw.update_persistent(handle, mesh);
+        }
+        if !buffer.is_persistent_in(&self.debug_draw, handle) {
+            return false;
+        }
+        let call = buffer.debug_draw.make_call(&mesh);
+        buffer.persistent_updates.push((handle, Some(call)));
+        true
+    }
+
+    /// Stops a persistent draw, once the buffer is merged. Returns `false` if it was already
+    /// removed.
+    pub fn remove_persistent(&mut self, handle: DebugDrawHandle) -> bool {
+        let buffer = &mut *self.buffer;
+        if buffer.debug_draw.remove_persistent(handle) {
+            return true;
+        }
+        if !buffer.is_persistent_in(&self.debug_draw, handle) {
+            return false;
+        }
+        buffer.persistent_updates.push((handle, None));
+        true
+    }
+
+    /// Returns a new sender of the main [`DebugDraw`], drawing with the default settings.
+    pub fn sender(&self) -> DebugDrawSender {
+        self.debug_draw.sender()
+    }
+
+    /// Makes a sender of the main [`DebugDraw`] reachable from anywhere with
+    /// [`global_debug_draw`](crate::global_debug_draw). Only the first call has an effect.
+    pub fn install_global_sender(&self) {
+        self.debug_draw.install_global_sender();
+    }
+}
+
+/// Pops its transform from the [`DebugDrawer`] when dropped, and derefs to it in the meantime.
+pub struct DebugDrawerTransformGuard<'a, 'w, 's> {
+    debug_drawer: &'a mut DebugDrawer<'w, 's>,
+}
+
+impl<'w, 's> Deref for DebugDrawerTransformGuard<'_, 'w, 's> {
+    type Target = DebugDrawer<'w, 's>;
+
+    fn deref(&self) -> &Self::Target {
+        self.debug_drawer
+    }
+}
+
+impl DerefMut for DebugDrawerTransformGuard<'_, '_, '_> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.debug_drawer
+    }
+}
+
+impl Drop for DebugDrawerTransformGuard<'_, '_, '_> {
+    fn drop(&mut self) {
+        self.debug_drawer.pop_transform();
+    }
+}
+
+/// The draws of a [`DebugDrawer`], and the changes to persistent draws of other buffers.
+#[derive(Default)]
+pub(crate) struct DebugDrawerBuffer {
+    debug_draw: DebugDraw,
+    persistent_updates: Vec<(DebugDrawHandle, Option<DebugDrawCall>)>,
+}
+
+impl DebugDrawerBuffer {
+    /// Whether a persistent draw exists in the main resource, and isn't removed by this buffer.
+    fn is_persistent_in(&self, debug_draw: &DebugDraw, handle: DebugDrawHandle) -> bool {
+        debug_draw.persistent.contains_key(&handle)
+            && !self
+                .persistent_updates
+                .iter()
+                .any(|(update_handle, call)| *update_handle == handle && call.is_none())
+    }
+}
+
+#[doc(hidden)]
+pub struct DebugDrawerState {
+    debug_draw: ComponentId,
+    buffer: Arc<Mutex<DebugDrawerBuffer>>,
+}
+
+/// Every [`DebugDrawer`] buffer, in registration order.
+#[derive(Resource, Default)]
+pub(crate) struct DebugDrawerBuffers(Vec<Arc<Mutex<DebugDrawerBuffer>>>);
+
+unsafe impl SystemParam for DebugDrawer<'_, '_> {
+    type State = DebugDrawerState;
+    type Item<'w, 's> = DebugDrawer<'w, 's>;
+
+    fn init_state(world: &mut World, system_meta: &mut SystemMeta) -> Self::State {
+        let buffer = Arc::new(Mutex::new(DebugDrawerBuffer::default()));
+        world
+            .get_resource_or_insert_with(DebugDrawerBuffers::default)
+            .0
+            .push(buffer.clone());
+        DebugDrawerState {
+            debug_draw: <Res<DebugDraw> as SystemParam>::init_state(world, system_meta),
+            buffer,
+        }
+    }
+
+    unsafe fn get_param<'w, 's>(
+        state: &'s mut Self::State,
+        system_meta: &SystemMeta,
+        world: &'w World,
+        change_tick: u32,
+    ) -> Self::Item<'w, 's> {
+        let debug_draw = <Res<DebugDraw> as SystemParam>::get_param(
+            &mut state.debug_draw,
+            system_meta,
+            world,
+            change_tick,
+        );
+        let mut buffer = state.buffer.lock().unwrap();
+        buffer.debug_draw.sync_settings(&debug_draw);
+        DebugDrawer { debug_draw, buffer }
+    }
+}
+
+impl DebugDraw {
+    /// Copies the settings that affect drawing from the main resource. Categories are left out:
+    /// disabled ones are dropped and the others styled when the merged draws are rendered.
+    fn sync_settings(&mut self, debug_draw: &DebugDraw) {
+        self.instancing = debug_draw.instancing;
+        self.feathering = debug_draw.feathering;
+        if debug_draw.fixed_tick && self.fixed_tick_count != debug_draw.fixed_tick_count {
+            self.fixed_calls.clear();
+            self.fixed_instances.clear();
+        }
+        self.fixed_tick = debug_draw.fixed_tick;
+        self.fixed_tick_count = debug_draw.fixed_tick_count;
+    }
+
+    fn merge_buffer(&mut self, buffer: &mut DebugDrawerBuffer) {
+        let persistent_updates = take(&mut buffer.persistent_updates);
+        let buffer = &mut buffer.debug_draw;
+        self.calls.append(&mut buffer.calls);
+        self.instances.append(&mut buffer.instances);
+        self.timed.append(&mut buffer.timed);
+        self.persistent.extend(buffer.persistent.drain());
+        for (handle, call) in persistent_updates {
+            match call {
+                Some(call) => {
+                    if let Some(persistent_call) = self.persistent.get_mut(&handle) {
+                        *persistent_call = call;
+                    }
+                }
+                None => {
+                    self.persistent.remove(&handle);
+                }
+            }
+        }
+        self.tessellation_time += take(&mut buffer.tessellation_time);
+        // Fixed tick draws are kept until the next tick, whether or not the system runs in it.
+        if buffer.fixed_tick_count != self.fixed_tick_count {
+            buffer.fixed_calls.clear();
+            buffer.fixed_instances.clear();
+        }
+        self.calls.extend(buffer.fixed_calls.iter().cloned());
+        self.instances
+            .extend(buffer.fixed_instances.iter().cloned());
+    }
+}
+
+/// Gathers the draws submitted through [`DebugDrawer`]s and
+/// [`DebugDrawSender`](crate::DebugDrawSender)s.
+pub(crate) fn debug_draw_merge(
+    mut debug_draw: ResMut<DebugDraw>,
+    debug_drawer_buffers: Res<DebugDrawerBuffers>,
+) {
+    debug_draw.receive_sent();
+    for buffer in debug_drawer_buffers.0.iter() {
+        debug_draw.merge_buffer(&mut buffer.lock().unwrap());
+    }
+}
@@ -0,0 +1,143 @@
+use std::collections::{BTreeMap, HashMap};
+
+use bevy::{prelude::*, render::view::RenderLayers};
+
+use crate::{DebugDraw, DebugDrawMesh, DebugDrawVertex};
+
+impl DebugDraw {
+    /// Adds a fringe of the given width in screen pixels around every mesh, fading to
+    /// transparent, to smooth edges when MSAA is off. `None` disables it.
+    pub fn set_feathering(&mut self, feathering: Option<f32>) {
+        self.feathering = feathering;
+    }
+
+    pub fn feathering(&self) -> Option<f32> {
+        self.feathering
+    }
+}
+
+impl DebugDrawMesh {
+    /// Returns a copy of this mesh with a fringe of the given width around its outline, whose alpha
+    /// falls to zero. Holes get a fringe too.
+    pub fn feathered(&self, width: f32) -> DebugDrawMesh {
+        let mut mesh = self.clone();
+
+        // Vertices are welded by position first, as some meshes don't share vertices between
+        // their triangles.
+        let mut welded: HashMap<(u32, u32), u32> = HashMap::new();
+        let weld_ids = self
+            .vertices
+            .iter()
+            .map(|vertex| {
+                let next_id = welded.len() as u32;
+                *welded
+                    .entry((vertex.position.x.to_bits(), vertex.position.y.to_bits()))
+                    .or_insert(next_id)
+            })
+            .collect::<Vec<_>>();
+
+        let mut edge_counts: HashMap<(u32, u32), u32> = HashMap::new();
+        for triangle in self.indices.chunks_exact(3) {
+            for side in 0..3 {
+                let a = weld_ids[triangle[side] as usize];
+                let b = weld_ids[triangle[(side + 1) % 3] as usize];
+                *edge_counts.entry((a.min(b), a.max(b))).or_default() += 1;
+            }
+        }
+
+        // Boundary edges with their outward normal, and the normals gathered at each of their
+        // vertices.
+        let mut edges = vec![];
+        let mut vertex_normals: BTreeMap<u32, (u32, Vec<Vec2>)> = BTreeMap::new();
+        for triangle in self.indices.chunks_exact(3) {
+            for side in 0..3 {
+                let (a, b, c) = (
+                    triangle[side],
+                    triangle[(side + 1) % 3],
+                    triangle[(side + 2) % 3],
+                );
+                let (weld_a, weld_b) = (weld_ids[a as usize], weld_ids[b as usize]);
+                if edge_counts[&(weld_a.min(weld_b), weld_a.max(weld_b))] != 1 {
+                    continue;
+                }
+                let position_a = self.vertices[a as usize].position;
+                let position_b = self.vertices[b as usize].position;
+                let position_c = self.vertices[c as usize].position;
+                let mut normal = (position_b - position_a).perp().normalize_or_zero();
+                if normal.dot(position_c - position_a) > 0. {
+                    normal = -normal;
+                }
+                edges.push((a, b));
+                for (index, weld_id) in [(a, weld_a), (b, weld_b)] {
+                    vertex_normals
+                        .entry(weld_id)
+                        .or_insert_with(|| (index, vec![]))
+                        .1
+                        .push(normal);
+                }
+            }
+        }
+
+        // The outer vertex of each boundary vertex is pushed along the averaged normal, lengthened
+        // at corners so the fringe keeps its width.
+        let mut outer_indices: HashMap<u32, u32> = HashMap::new();
+        for (weld_id, (index, normals)) in vertex_normals.iter() {
+            let direction = normals.iter().sum::<Vec2>().normalize_or_zero();
+            let cosine = normals
+                .iter()
+                .map(|normal| normal.dot(direction))
+                .fold(1., f32::min)
+                .max(0.25);
+            let offset = direction * width / cosine;
+
+            let vertex = self.vertices[*index as usize];
+            let mut color = vertex.color;
+            color.set_a(0.);
+            outer_indices.insert(*weld_id, mesh.vertices.len() as u32);
+            mesh.vertices.push(DebugDrawVertex {
+                position: vertex.position + offset,
+                color,
+                uv: vertex.uv,
+            });
+        }
+
+        for (a, b) in edges {
+            let outer_a = outer_indices[&weld_ids[a as usize]];
+            let outer_b = outer_indices[&weld_ids[b as usize]];
+            mesh.indices
+                .extend_from_slice(&[a, b, outer_b, a, outer_b, outer_a]);
+        }
+        mesh
+    }
+}
+
+/// The size of a logical pixel in world units, for the first orthographic camera rendering the
+/// given layers.
+pub(crate) fn world_units_per_pixel<F: bevy::ecs::query::ReadOnlyWorldQuery>(
+    render_layers: RenderLayers,
+    projection_query: &Query<
+        (
+            &Camera,
+            &OrthographicProjection,
+            &GlobalTransform,
+            Option<&RenderLayers>,
+        ),
+        F,
+    >,
+) -> f32 {
+    projection_query
+        .iter()
+        .filter(|(camera, _, _, camera_layers)| {
+            camera.is_active
+                && camera_layers
+                    .copied()
+                    .unwrap_or_default()
+                    .intersects(&render_layers)
+        })
+        .find_map(|(camera, projection, transform, _)| {
+            let viewport_width = camera.logical_viewport_size()?.x;
+            let scale = transform.to_scale_rotation_translation().0.x;
+            (viewport_width > 0.).then(|| projection.area.width() / viewport_width * scale)
+        })
+        .unwrap_or(1.)
+}
@@ -0,0 +1,54 @@
+use bevy::{prelude::*, time::fixed_timestep::run_fixed_update_schedule};
+
+use crate::{DebugDraw, DebugDraw3d};
+
+/// Starts a new fixed tick for [`DebugDraw`] and [`DebugDraw3d`] in
+/// [`CoreSchedule::FixedUpdate`]. Systems drawing there should run `.after(DebugDrawFixedSystem)`,
+/// otherwise their draws may be cleared along with those of the previous tick.
+///
+/// Draws issued during a fixed tick replace those of the previous tick, and are rendered every
+/// frame until the next tick runs, so they neither flicker nor pile up when a frame runs zero or
+/// several ticks.
+#[derive(Debug, Hash, PartialEq, Eq, Clone, SystemSet)]
+pub struct DebugDrawFixedSystem;
+
+pub(crate) fn configure_debug_draw_fixed_system(app: &mut App) {
+    app.add_system(
+        debug_fixed_tick_begin
+            .in_set(DebugDrawFixedSystem)
+            .in_schedule(CoreSchedule::FixedUpdate),
+    )
+    .add_system(
+        debug_fixed_tick_end
+            .in_base_set(CoreSet::FixedUpdate)
+            .after(run_fixed_update_schedule),
+    );
+}
+
+fn debug_fixed_tick_begin(
+    debug_draw: Option<ResMut<DebugDraw>>,
+    debug_draw_3d: Option<ResMut<DebugDraw3d>>,
+) {
+    if let Some(mut debug_draw) = debug_draw {
+        debug_draw.fixed_tick = true;
+        debug_draw.fixed_tick_count += 1;
+        debug_draw.fixed_calls.clear();
+        debug_draw.fixed_instances.clear();
+    }
+    if let Some(mut debug_draw_3d) = debug_draw_3d {
+        debug_draw_3d.fixed_tick = true;
+        debug_draw_3d.fixed_calls.clear();
+    }
+}
+
+fn debug_fixed_tick_end(
+    debug_draw: Option<ResMut<DebugDraw>>,
+    debug_draw_3d: Option<ResMut<DebugDraw3d>>,
+) {
+    if let Some(mut debug_draw) = debug_draw {
+        debug_draw.fixed_tick = false;
+    }
+    if let Some(mut debug_draw_3d) = debug_draw_3d {
+        debug_draw_3d.fixed_tick = false;
+    }
+}
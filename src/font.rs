@@ -0,0 +1,170 @@
+use std::{
+    collections::HashMap,
+    fmt,
+    hash::{Hash, Hasher},
+    sync::{
+        mpsc::{self, Receiver},
+        Arc, Mutex, OnceLock, RwLock,
+    },
+};
+
+use bevy::{asset::AssetIoError, prelude::*, tasks::IoTaskPool};
+use lazy_static::lazy_static;
+use ttf_parser::{Face, FaceParsingError, GlyphId};
+
+use crate::Glyph;
+
+lazy_static! {
+    static ref DEFAULT_FACE: Face<'static> =
+        Face::parse(include_bytes!("./FiraSans-Bold.ttf"), 0).unwrap();
+    static ref DEFAULT_FONT: DebugTextFontData = DebugTextFontData::default();
+}
+
+/// A font registered in [`DebugTextFonts`]. The default is the bundled Fira Sans Bold. A font is
+/// released once neither [`DebugTextFonts`] nor any text uses it.
+#[derive(Clone, Debug, Default)]
+pub struct DebugTextFont(Option<Arc<DebugTextFontData>>);
+
+impl DebugTextFont {
+    pub const DEFAULT: Self = Self(None);
+
+    /// The data and face of the font, or the default ones while the font is loading. Fonts only
+    /// keep their file, so their face is parsed again every time.
+    pub(crate) fn resolve(&self) -> (&DebugTextFontData, Face<'_>) {
+        if let Some(font) = &self.0 {
+            if let Some(face) = font.data.get().and_then(|data| Face::parse(data, 0).ok()) {
+                return (font, face);
+            }
+        }
+        (&DEFAULT_FONT, DEFAULT_FACE.clone())
+    }
+}
+
+impl PartialEq for DebugTextFont {
+    fn eq(&self, other: &Self) -> bool {
+        match (&self.0, &other.0) {
+            (Some(font), Some(other)) => Arc::ptr_eq(font, other),
+            (font, other) => font.is_none() && other.is_none(),
+        }
+    }
+}
+
+impl Eq for DebugTextFont {}
+
+impl Hash for DebugTextFont {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.as_ref().map(Arc::as_ptr).hash(state);
+    }
+}
+
+/// The file of a font, once it is read, and the glyphs and kerning computed from it.
+#[derive(Default)]
+pub(crate) struct DebugTextFontData {
+    data: OnceLock<Vec<u8>>,
+    pub(crate) glyphs: RwLock<HashMap<(GlyphId, i8), Glyph>>,
+    pub(crate) kerning: RwLock<HashMap<(GlyphId, GlyphId), i16>>,
+}
+
+impl fmt::Debug for DebugTextFontData {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DebugTextFontData")
+            .field("loaded", &self.data.get().is_some())
+            .finish_non_exhaustive()
+    }
+}
+
+/// Registers fonts for [`crate::DebugText`]. Every [`DebugTextFont`] shares its font data, so it
+/// can be used in any app, and the data lives as long as this resource or any text keeps it.
+#[derive(Resource, Default)]
+pub struct DebugTextFonts {
+    fonts: Vec<DebugTextFont>,
+    pending: Vec<(DebugTextFont, PendingFont)>,
+}
+
+enum PendingFont {
+    Handle(Handle<Font>),
+    Loading(Mutex<Receiver<Result<Vec<u8>, AssetIoError>>>),
+}
+
+impl DebugTextFonts {
+    /// Registers a TrueType or OpenType font. For collections, the first font is used.
+    pub fn add_bytes(
+        &mut self,
+        data: impl Into<Vec<u8>>,
+    ) -> Result<DebugTextFont, FaceParsingError> {
+        let data = data.into();
+        Face::parse(&data, 0)?;
+        let font = DebugTextFont(Some(Arc::new(DebugTextFontData {
+            data: OnceLock::from(data),
+            ..Default::default()
+        })));
+        self.fonts.push(font.clone());
+        Ok(font)
+    }
+
+    /// Registers a font asset loaded from a file. The default font is used until its data is
+    /// read, which happens once the asset server knows its path.
+    ///
+    /// `Font` assets don't keep their data, so it is read again from the file. Handles created
+    /// with `Assets::add` have no file, and their font stays the default one: register the data
+    /// of such fonts with [`DebugTextFonts::add_bytes`] instead.
+    pub fn add(&mut self, handle: Handle<Font>) -> DebugTextFont {
+        let font = DebugTextFont(Some(Arc::default()));
+        self.fonts.push(font.clone());
+        self.pending
+            .push((font.clone(), PendingFont::Handle(handle)));
+        font
+    }
+
+    pub fn is_loaded(&self, font: &DebugTextFont) -> bool {
+        font.0
+            .as_ref()
+            .map_or(true, |font| font.data.get().is_some())
+    }
+}
+
+pub(crate) fn debug_text_fonts_loader(
+    mut fonts: ResMut<DebugTextFonts>,
+    asset_server: Option<Res<AssetServer>>,
+) {
+    let Some(asset_server) = asset_server else {
+        return;
+    };
+    fonts.pending.retain_mut(|(font, pending)| match pending {
+        PendingFont::Handle(handle) => {
+            let Some(path) = asset_server.get_handle_path(handle.id()) else {
+                error!("A debug text font has no path, register its data with add_bytes instead");
+                return false;
+            };
+            let path = path.path().to_owned();
+            let asset_server = asset_server.clone();
+            let (sender, receiver) = mpsc::channel();
+            IoTaskPool::get()
+                .spawn(async move {
+                    let _ = sender.send(asset_server.asset_io().load_path(&path).await);
+                })
+                .detach();
+            *pending = PendingFont::Loading(Mutex::new(receiver));
+            true
+        }
+        PendingFont::Loading(receiver) => match receiver.get_mut().unwrap().try_recv() {
+            Ok(Ok(data)) => {
+                match Face::parse(&data, 0) {
+                    Ok(_) => {
+                        if let Some(font) = &font.0 {
+                            let _ = font.data.set(data);
+                        }
+                    }
+                    Err(error) => error!("Failed to parse a debug text font: {}", error),
+                }
+                false
+            }
+            Ok(Err(error)) => {
+                error!("Failed to read a debug text font: {}", error);
+                false
+            }
+            Err(mpsc::TryRecvError::Empty) => true,
+            Err(mpsc::TryRecvError::Disconnected) => false,
+        },
+    });
+}
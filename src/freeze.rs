@@ -0,0 +1,211 @@
+use bevy::{
+    input::mouse::{MouseScrollUnit, MouseWheel},
+    math::Affine2,
+    prelude::*,
+    window::PrimaryWindow,
+};
+
+use crate::{DebugDraw, DebugDrawInstance, DebugDrawSpace};
+
+/// Adds hotkeys to freeze, step, pan and zoom the debug drawing, configured by
+/// [`DebugDrawFreezeControls`].
+pub struct DebugDrawFreezePlugin;
+
+impl Plugin for DebugDrawFreezePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<DebugDraw>()
+            .init_resource::<DebugDrawFreezeControls>()
+            .add_system(debug_freeze_controls);
+    }
+}
+
+#[derive(Resource, Clone, Debug)]
+pub struct DebugDrawFreezeControls {
+    pub toggle: KeyCode,
+    /// Advances a frozen drawing by one frame, or freezes a live one.
+    pub step: KeyCode,
+    /// Pans the frozen drawing while held and dragged. The mouse wheel zooms it.
+    pub pan: MouseButton,
+    pub reset_view: KeyCode,
+}
+
+impl Default for DebugDrawFreezeControls {
+    fn default() -> Self {
+        Self {
+            toggle: KeyCode::F9,
+            step: KeyCode::F8,
+            pan: MouseButton::Middle,
+            reset_view: KeyCode::F7,
+        }
+    }
+}
+
+impl DebugDraw {
+    /// Keeps the current frame on screen, ignoring later draws until [`DebugDraw::unfreeze`].
+    /// Timed draws are paused.
+    pub fn freeze(&mut self) {
+        if self.frozen.is_none() {
+            // The frame is captured before category settings are applied, when it's rendered.
+            self.frozen = Some(Default::default());
+            self.step_requested = true;
+            self.freeze_transform = Affine2::IDENTITY;
+        }
+    }
+
+    pub fn unfreeze(&mut self) {
+        self.frozen = None;
+        self.step_requested = false;
+        self.freeze_transform = Affine2::IDENTITY;
+    }
+
+    pub fn toggle_freeze(&mut self) {
+        if self.is_frozen() {
+            self.unfreeze();
+        } else {
+            self.freeze();
+        }
+    }
+
+    pub fn is_frozen(&self) -> bool {
+        self.frozen.is_some()
+    }
+
+    /// Replaces the frozen frame with the next one, or freezes a live drawing.
+    pub fn step(&mut self) {
+        if self.is_frozen() {
+            self.step_requested = true;
+        } else {
+            self.freeze();
+        }
+    }
+
+    /// The transform applied to the world space meshes of the frozen frame.
+    pub fn freeze_transform(&self) -> Affine2 {
+        self.freeze_transform
+    }
+
+    pub fn set_freeze_transform(&mut self, transform: Affine2) {
+        self.freeze_transform = transform;
+    }
+
+    /// Moves the frozen frame by the given world offset.
+    pub fn pan_frozen(&mut self, offset: Vec2) {
+        self.freeze_transform = Affine2::from_translation(offset) * self.freeze_transform;
+    }
+
+    /// Scales the frozen frame around a world position.
+    pub fn zoom_frozen(&mut self, factor: f32, center: Vec2) {
+        self.freeze_transform = Affine2::from_translation(center)
+            * Affine2::from_scale(Vec2::splat(factor))
+            * Affine2::from_translation(-center)
+            * self.freeze_transform;
+    }
+
+    /// Prepares the calls to render this frame, from the new draws or the frozen frame, styled by
+    /// their category.
+    pub(crate) fn begin_frame(&mut self, delta_seconds: f32) {
+        if self.frozen.is_none() || std::mem::take(&mut self.step_requested) {
+            self.flush_retained(delta_seconds);
+            if let Some((calls, instances)) = &mut self.frozen {
+                calls.clone_from(&self.calls);
+                instances.clone_from(&self.instances);
+            }
+        }
+        if let Some((calls, instances)) = &self.frozen {
+            self.calls.clear();
+            self.calls.extend(calls.iter().cloned().map(|mut call| {
+                if call.space == DebugDrawSpace::World {
+                    call.mesh.transform(self.freeze_transform);
+                }
+                call
+            }));
+            // Instances are always in world space.
+            self.instances.clear();
+            self.instances.extend(
+                instances
+                    .iter()
+                    .cloned()
+                    .map(|(target, category, instance)| {
+                        (
+                            target,
+                            category,
+                            transform_instance(self.freeze_transform, instance),
+                        )
+                    }),
+            );
+        }
+        self.apply_category_settings();
+    }
+}
+
+/// Moves, scales and rotates an instance like its outline would be. Skews are not representable,
+/// so they only scale the instance along its own axes.
+fn transform_instance(transform: Affine2, mut instance: DebugDrawInstance) -> DebugDrawInstance {
+    let x_axis = transform
+        .matrix2
+        .mul_vec2(Vec2::from_angle(instance.rotation));
+    let y_axis = transform
+        .matrix2
+        .mul_vec2(Vec2::from_angle(instance.rotation).perp());
+    instance.center = transform.transform_point2(instance.center);
+    instance.size *= Vec2::new(x_axis.length(), y_axis.length());
+    instance.rotation = x_axis.y.atan2(x_axis.x);
+    instance
+}
+
+#[allow(clippy::too_many_arguments)]
+fn debug_freeze_controls(
+    mut debug_draw: ResMut<DebugDraw>,
+    controls: Res<DebugDrawFreezeControls>,
+    keys: Option<Res<Input<KeyCode>>>,
+    mouse_buttons: Option<Res<Input<MouseButton>>>,
+    mut mouse_wheel: EventReader<MouseWheel>,
+    window_query: Query<&Window, With<PrimaryWindow>>,
+    camera_query: Query<(&Camera, &GlobalTransform), With<OrthographicProjection>>,
+    mut last_cursor: Local<Option<Vec2>>,
+) {
+    if let Some(keys) = keys {
+        if keys.just_pressed(controls.toggle) {
+            debug_draw.toggle_freeze();
+        }
+        if keys.just_pressed(controls.step) {
+            debug_draw.step();
+        }
+        if keys.just_pressed(controls.reset_view) {
+            debug_draw.set_freeze_transform(Affine2::IDENTITY);
+        }
+    }
+
+    let cursor = window_query
+        .get_single()
+        .ok()
+        .and_then(|window| window.cursor_position())
+        .and_then(|cursor| {
+            camera_query
+                .iter()
+                .filter(|(camera, _)| camera.is_active)
+                .min_by_key(|(camera, _)| camera.order)
+                .and_then(|(camera, transform)| camera.viewport_to_world_2d(transform, cursor))
+        });
+    let previous_cursor = std::mem::replace(&mut *last_cursor, cursor);
+    if !debug_draw.is_frozen() {
+        mouse_wheel.clear();
+        return;
+    }
+    let Some(cursor) = cursor else {
+        return;
+    };
+
+    if let (Some(previous_cursor), Some(mouse_buttons)) = (previous_cursor, mouse_buttons) {
+        if mouse_buttons.pressed(controls.pan) {
+            debug_draw.pan_frozen(cursor - previous_cursor);
+        }
+    }
+    for event in mouse_wheel.iter() {
+        let lines = match event.unit {
+            MouseScrollUnit::Line => event.y,
+            MouseScrollUnit::Pixel => event.y / 20.,
+        };
+        debug_draw.zoom_frozen(1.1_f32.powf(lines), cursor);
+    }
+}
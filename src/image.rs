@@ -0,0 +1,66 @@
+use bevy::prelude::*;
+
+use crate::{DebugDrawDrawable, DebugDrawMesh, DebugDrawVertex};
+
+/// A textured quad showing the `uv_rect` region of an image.
+#[derive(Clone, Debug)]
+pub struct DebugImage {
+    pub image: Handle<Image>,
+    pub position: Vec2,
+    pub size: Vec2,
+    /// The region of the image to show, in normalized coordinates with y down.
+    pub uv_rect: Rect,
+    pub rotation: f32,
+    pub tint: Color,
+    pub depth: f32,
+}
+
+impl Default for DebugImage {
+    fn default() -> Self {
+        Self {
+            image: Handle::default(),
+            position: Vec2::ZERO,
+            size: Vec2::ZERO,
+            uv_rect: Rect::new(0., 0., 1., 1.),
+            rotation: 0.,
+            tint: Color::WHITE,
+            depth: 0.,
+        }
+    }
+}
+
+impl DebugImage {
+    /// Shows a frame of a texture atlas, at its size in pixels.
+    pub fn from_atlas(atlas: &TextureAtlas, index: usize) -> Self {
+        let frame = atlas.textures[index];
+        Self {
+            image: atlas.texture.clone(),
+            size: frame.size(),
+            uv_rect: Rect::from_corners(frame.min / atlas.size, frame.max / atlas.size),
+            ..Default::default()
+        }
+    }
+}
+
+impl DebugDrawDrawable for DebugImage {
+    fn to_mesh(&self) -> DebugDrawMesh {
+        let rotation = Vec2::from_angle(self.rotation);
+        let corner = |corner: Vec2, uv: Vec2| DebugDrawVertex {
+            position: self.position + rotation.rotate(self.size * corner),
+            color: self.tint,
+            uv,
+        };
+        let uv = self.uv_rect;
+        DebugDrawMesh {
+            vertices: vec![
+                corner(Vec2::new(0.5, 0.5), Vec2::new(uv.max.x, uv.min.y)),
+                corner(Vec2::new(-0.5, 0.5), uv.min),
+                corner(Vec2::new(0.5, -0.5), uv.max),
+                corner(Vec2::new(-0.5, -0.5), Vec2::new(uv.min.x, uv.max.y)),
+            ],
+            indices: vec![0, 1, 2, 3, 2, 1],
+            depth: self.depth,
+            texture: Some(self.image.clone()),
+        }
+    }
+}
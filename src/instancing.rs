@@ -0,0 +1,378 @@
+use std::{cmp::Ordering, collections::BTreeMap, ops::Range};
+
+use bevy::{
+    asset::load_internal_asset,
+    core_pipeline::core_2d::Transparent2d,
+    ecs::system::{lifetimeless::SRes, SystemParamItem},
+    prelude::*,
+    reflect::TypeUuid,
+    render::{
+        extract_resource::{ExtractResource, ExtractResourcePlugin},
+        render_phase::{
+            AddRenderCommand, DrawFunctions, PhaseItem, RenderCommand, RenderCommandResult,
+            RenderPhase, SetItemPipeline, TrackedRenderPass,
+        },
+        render_resource::*,
+        renderer::{RenderDevice, RenderQueue},
+        texture::BevyDefault,
+        view::{ExtractedView, Msaa, RenderLayers, ViewTarget},
+        Extract, ExtractSchedule, RenderApp, RenderSet,
+    },
+    sprite::{Mesh2dPipeline, SetMesh2dViewBindGroup},
+    utils::FloatOrd,
+};
+use bytemuck::{Pod, Zeroable};
+
+use crate::{debug_headless_renderer, debug_renderer, DebugDraw, DebugDrawSystem};
+
+const DEBUG_DRAW_INSTANCING_SHADER_HANDLE: HandleUntyped =
+    HandleUntyped::weak_from_u64(Shader::TYPE_UUID, 4293610185329207418);
+
+/// Renders circles, rectangles and lines as GPU instances instead of tessellating them on the
+/// CPU. Anything that can't be expressed as an instance still goes through the mesh path.
+/// Instanced draws are rendered in front of the meshes of the same depth.
+pub struct DebugDrawInstancingPlugin;
+
+impl Plugin for DebugDrawInstancingPlugin {
+    fn build(&self, app: &mut App) {
+        load_internal_asset!(
+            app,
+            DEBUG_DRAW_INSTANCING_SHADER_HANDLE,
+            "instancing.wgsl",
+            Shader::from_wgsl
+        );
+
+        app.init_resource::<DebugDraw>()
+            .init_resource::<DebugDrawInstances>()
+            .add_plugin(ExtractResourcePlugin::<DebugDrawInstances>::default())
+            .add_system(
+                debug_instance_collector
+                    .in_set(DebugDrawSystem)
+                    .after(debug_renderer)
+                    .after(debug_headless_renderer),
+            );
+        app.world.resource_mut::<DebugDraw>().instancing = true;
+
+        if let Ok(render_app) = app.get_sub_app_mut(RenderApp) {
+            render_app
+                .init_resource::<DebugDrawInstanceBuffer>()
+                .init_resource::<DebugDrawInstancePipeline>()
+                .init_resource::<SpecializedRenderPipelines<DebugDrawInstancePipeline>>()
+                .add_render_command::<Transparent2d, DrawDebugDrawInstances>()
+                .add_system(extract_debug_draw_view_layers.in_schedule(ExtractSchedule))
+                .add_system(queue_debug_draw_instances.in_set(RenderSet::Queue));
+        }
+    }
+}
+
+/// A primitive expanded on the GPU. The shape is a rectangle when `segments` is zero, and a
+/// regular polygon inscribed in `size` otherwise.
+#[derive(Default, Debug, Clone, Copy)]
+pub struct DebugDrawInstance {
+    pub center: Vec2,
+    pub size: Vec2,
+    pub rotation: f32,
+    pub segments: u8,
+    pub start_color: Color,
+    pub end_color: Color,
+    pub depth: f32,
+}
+
+#[derive(Resource, Clone, Default, ExtractResource)]
+struct DebugDrawInstances {
+    instances: Vec<DebugDrawInstance>,
+    batches: Vec<(RenderLayers, f32, Range<u32>)>,
+}
+
+fn debug_instance_collector(
+    mut debug_draw: ResMut<DebugDraw>,
+    mut debug_instances: ResMut<DebugDrawInstances>,
+    camera_query: Query<Option<&RenderLayers>, With<Camera>>,
+) {
+    let debug_draw = debug_draw.as_mut();
+    debug_draw
+        .instances
+        .sort_by(|(_, _, a), (_, _, b)| a.depth.partial_cmp(&b.depth).unwrap_or(Ordering::Equal));
+    let mut batches: BTreeMap<(RenderLayers, FloatOrd), Vec<DebugDrawInstance>> = BTreeMap::new();
+    let screen_layer = debug_draw.screen_layer();
+    for (target, _, instance) in debug_draw.instances.drain(..) {
+        let key = (
+            target.render_layers(&camera_query, screen_layer),
+            FloatOrd(debug_draw.depth_mapping.z_for(instance.depth)),
+        );
+        let z = debug_draw.instance_z.get(&key).copied().unwrap_or(key.1 .0);
+        batches
+            .entry((key.0, FloatOrd(z)))
+            .or_default()
+            .push(instance);
+    }
+
+    let debug_instances = debug_instances.as_mut();
+    debug_instances.instances.clear();
+    debug_instances.batches.clear();
+    for ((render_layers, z), mut batch) in batches.into_iter() {
+        let start = debug_instances.instances.len() as u32;
+        debug_instances.instances.append(&mut batch);
+        let end = debug_instances.instances.len() as u32;
+        debug_instances
+            .batches
+            .push((render_layers, z.0, start..end));
+    }
+}
+
+/// The render layers of a 2D camera, copied onto its render world view.
+#[derive(Component)]
+struct DebugDrawViewLayers(RenderLayers);
+
+#[allow(clippy::type_complexity)]
+fn extract_debug_draw_view_layers(
+    mut commands: Commands,
+    camera_query: Extract<Query<(Entity, Option<&RenderLayers>), With<Camera2d>>>,
+) {
+    for (camera_entity, render_layers) in camera_query.iter() {
+        commands
+            .get_or_spawn(camera_entity)
+            .insert(DebugDrawViewLayers(
+                render_layers.copied().unwrap_or_default(),
+            ));
+    }
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Pod, Zeroable)]
+struct DebugDrawInstanceData {
+    center: [f32; 2],
+    size: [f32; 2],
+    rotation: f32,
+    segments: f32,
+    start_color: [f32; 4],
+    end_color: [f32; 4],
+}
+
+impl From<&DebugDrawInstance> for DebugDrawInstanceData {
+    fn from(instance: &DebugDrawInstance) -> Self {
+        Self {
+            center: instance.center.into(),
+            size: instance.size.into(),
+            rotation: instance.rotation,
+            segments: instance.segments as f32,
+            start_color: [
+                instance.start_color.r(),
+                instance.start_color.g(),
+                instance.start_color.b(),
+                instance.start_color.a(),
+            ],
+            end_color: [
+                instance.end_color.r(),
+                instance.end_color.g(),
+                instance.end_color.b(),
+                instance.end_color.a(),
+            ],
+        }
+    }
+}
+
+#[derive(Resource)]
+struct DebugDrawInstanceBuffer {
+    instances: BufferVec<DebugDrawInstanceData>,
+}
+
+impl Default for DebugDrawInstanceBuffer {
+    fn default() -> Self {
+        Self {
+            instances: BufferVec::new(BufferUsages::VERTEX),
+        }
+    }
+}
+
+#[derive(Resource)]
+struct DebugDrawInstancePipeline {
+    view_layout: BindGroupLayout,
+}
+
+impl FromWorld for DebugDrawInstancePipeline {
+    fn from_world(world: &mut World) -> Self {
+        Self {
+            view_layout: world.resource::<Mesh2dPipeline>().view_layout.clone(),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Hash, PartialEq, Eq)]
+struct DebugDrawInstancePipelineKey {
+    hdr: bool,
+    msaa_samples: u32,
+}
+
+impl SpecializedRenderPipeline for DebugDrawInstancePipeline {
+    type Key = DebugDrawInstancePipelineKey;
+
+    fn specialize(&self, key: Self::Key) -> RenderPipelineDescriptor {
+        let instance_layout = VertexBufferLayout::from_vertex_formats(
+            VertexStepMode::Instance,
+            vec![
+                // center
+                VertexFormat::Float32x2,
+                // size
+                VertexFormat::Float32x2,
+                // rotation
+                VertexFormat::Float32,
+                // segments
+                VertexFormat::Float32,
+                // start color
+                VertexFormat::Float32x4,
+                // end color
+                VertexFormat::Float32x4,
+            ],
+        );
+
+        let format = if key.hdr {
+            ViewTarget::TEXTURE_FORMAT_HDR
+        } else {
+            TextureFormat::bevy_default()
+        };
+
+        RenderPipelineDescriptor {
+            vertex: VertexState {
+                shader: DEBUG_DRAW_INSTANCING_SHADER_HANDLE.typed::<Shader>(),
+                entry_point: "vertex".into(),
+                shader_defs: vec![],
+                buffers: vec![instance_layout],
+            },
+            fragment: Some(FragmentState {
+                shader: DEBUG_DRAW_INSTANCING_SHADER_HANDLE.typed::<Shader>(),
+                shader_defs: vec![],
+                entry_point: "fragment".into(),
+                targets: vec![Some(ColorTargetState {
+                    format,
+                    blend: Some(BlendState::ALPHA_BLENDING),
+                    write_mask: ColorWrites::ALL,
+                })],
+            }),
+            layout: vec![self.view_layout.clone()],
+            push_constant_ranges: vec![],
+            primitive: PrimitiveState {
+                front_face: FrontFace::Ccw,
+                cull_mode: None,
+                unclipped_depth: false,
+                polygon_mode: PolygonMode::Fill,
+                conservative: false,
+                topology: PrimitiveTopology::TriangleList,
+                strip_index_format: None,
+            },
+            depth_stencil: None,
+            multisample: MultisampleState {
+                count: key.msaa_samples,
+                mask: !0,
+                alpha_to_coverage_enabled: false,
+            },
+            label: Some("debug_draw_instancing_pipeline".into()),
+        }
+    }
+}
+
+#[derive(Component)]
+struct DebugDrawInstanceBatch {
+    range: Range<u32>,
+}
+
+#[allow(clippy::too_many_arguments)]
+fn queue_debug_draw_instances(
+    mut commands: Commands,
+    draw_functions: Res<DrawFunctions<Transparent2d>>,
+    render_device: Res<RenderDevice>,
+    render_queue: Res<RenderQueue>,
+    debug_instances: Option<Res<DebugDrawInstances>>,
+    mut debug_instance_buffer: ResMut<DebugDrawInstanceBuffer>,
+    debug_instance_pipeline: Res<DebugDrawInstancePipeline>,
+    mut pipelines: ResMut<SpecializedRenderPipelines<DebugDrawInstancePipeline>>,
+    pipeline_cache: Res<PipelineCache>,
+    msaa: Res<Msaa>,
+    mut views: Query<(
+        &mut RenderPhase<Transparent2d>,
+        &ExtractedView,
+        Option<&DebugDrawViewLayers>,
+    )>,
+) {
+    debug_instance_buffer.instances.clear();
+    let Some(debug_instances) = debug_instances else {
+        return;
+    };
+    if debug_instances.instances.is_empty() {
+        return;
+    }
+    for instance in debug_instances.instances.iter() {
+        debug_instance_buffer.instances.push(instance.into());
+    }
+    debug_instance_buffer
+        .instances
+        .write_buffer(&render_device, &render_queue);
+
+    let batch_entities = debug_instances
+        .batches
+        .iter()
+        .map(|(render_layers, z, range)| {
+            let batch_entity = commands
+                .spawn(DebugDrawInstanceBatch {
+                    range: range.clone(),
+                })
+                .id();
+            (*render_layers, *z, batch_entity)
+        })
+        .collect::<Vec<_>>();
+    let draw_function = draw_functions.read().id::<DrawDebugDrawInstances>();
+    for (mut transparent_phase, view, view_layers) in views.iter_mut() {
+        let view_layers = view_layers
+            .map(|view_layers| view_layers.0)
+            .unwrap_or_default();
+        let pipeline = pipelines.specialize(
+            &pipeline_cache,
+            &debug_instance_pipeline,
+            DebugDrawInstancePipelineKey {
+                hdr: view.hdr,
+                msaa_samples: msaa.samples(),
+            },
+        );
+        for (render_layers, z, batch_entity) in batch_entities.iter() {
+            if !view_layers.intersects(render_layers) {
+                continue;
+            }
+            transparent_phase.add(Transparent2d {
+                sort_key: FloatOrd(*z),
+                entity: *batch_entity,
+                pipeline,
+                draw_function,
+                batch_range: None,
+            });
+        }
+    }
+}
+
+type DrawDebugDrawInstances = (
+    SetItemPipeline,
+    SetMesh2dViewBindGroup<0>,
+    DrawDebugDrawInstanceBatch,
+);
+
+struct DrawDebugDrawInstanceBatch;
+
+impl<P: PhaseItem> RenderCommand<P> for DrawDebugDrawInstanceBatch {
+    type Param = SRes<DebugDrawInstanceBuffer>;
+    type ViewWorldQuery = ();
+    type ItemWorldQuery = &'static DebugDrawInstanceBatch;
+
+    fn render<'w>(
+        _item: &P,
+        _view: (),
+        batch: &'w DebugDrawInstanceBatch,
+        debug_instance_buffer: SystemParamItem<'w, '_, Self::Param>,
+        pass: &mut TrackedRenderPass<'w>,
+    ) -> RenderCommandResult {
+        let Some(buffer) = debug_instance_buffer.into_inner().instances.buffer() else {
+            return RenderCommandResult::Failure;
+        };
+        pass.set_vertex_buffer(0, buffer.slice(..));
+        pass.draw(0..6, batch.range.clone());
+        RenderCommandResult::Success
+    }
+}
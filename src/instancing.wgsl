@@ -0,0 +1,58 @@
+#import bevy_sprite::mesh2d_view_bindings
+
+struct Vertex {
+    @builtin(vertex_index) index: u32,
+    @location(0) center: vec2<f32>,
+    @location(1) size: vec2<f32>,
+    @location(2) rotation: f32,
+    @location(3) segments: f32,
+    @location(4) start_color: vec4<f32>,
+    @location(5) end_color: vec4<f32>,
+};
+
+struct VertexOutput {
+    @builtin(position) clip_position: vec4<f32>,
+    @location(0) color: vec4<f32>,
+    @location(1) local: vec2<f32>,
+    @location(2) @interpolate(flat) segments: f32,
+};
+
+@vertex
+fn vertex(vertex: Vertex) -> VertexOutput {
+    var corners = array<vec2<f32>, 6>(
+        vec2<f32>(0.5, 0.5),
+        vec2<f32>(-0.5, 0.5),
+        vec2<f32>(0.5, -0.5),
+        vec2<f32>(-0.5, -0.5),
+        vec2<f32>(0.5, -0.5),
+        vec2<f32>(-0.5, 0.5),
+    );
+    let local = corners[vertex.index];
+    let scaled = local * vertex.size;
+    let c = cos(vertex.rotation);
+    let s = sin(vertex.rotation);
+    let position = vertex.center + vec2<f32>(c * scaled.x - s * scaled.y, s * scaled.x + c * scaled.y);
+
+    var out: VertexOutput;
+    out.clip_position = view.view_proj * vec4<f32>(position, 1.0, 1.0);
+    out.color = mix(vertex.start_color, vertex.end_color, local.x + 0.5);
+    out.local = local;
+    out.segments = vertex.segments;
+    return out;
+}
+
+@fragment
+fn fragment(in: VertexOutput) -> @location(0) vec4<f32> {
+    if in.segments > 0.0 {
+        // Regular polygon with its first corner on the local x axis and a circumradius of 0.5.
+        let tau = 6.28318530718;
+        let sector = tau / in.segments;
+        let angle = atan2(in.local.y, in.local.x) + tau;
+        let within_sector = angle - sector * floor(angle / sector);
+        let edge_distance = 0.5 * cos(sector * 0.5) / cos(within_sector - sector * 0.5);
+        if length(in.local) > edge_distance {
+            discard;
+        }
+    }
+    return in.color;
+}
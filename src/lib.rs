@@ -1,42 +1,337 @@
-use std::{cmp::Ordering, mem::take};
+use std::{
+    borrow::Cow,
+    cmp::Ordering,
+    collections::{BTreeMap, BTreeSet, HashMap, HashSet},
+    mem::{replace, swap, take},
+    ops::Bound,
+    sync::OnceLock,
+    time::Duration,
+};
 
 use bevy::{
+    asset::load_internal_asset,
+    math::Affine2,
     prelude::*,
-    render::{mesh::Indices, render_resource::PrimitiveTopology},
-    sprite::Mesh2dHandle,
+    render::{
+        mesh::{Indices, MeshVertexAttribute, VertexAttributeValues},
+        render_resource::PrimitiveTopology,
+        view::{RenderLayers, VisibilitySystems},
+    },
+    sprite::{Material2dPlugin, MaterialMesh2dBundle, Mesh2dHandle},
+    transform::TransformSystem,
+    utils::{FloatOrd, Instant},
+    window::PrimaryWindow,
 };
 
+/// Turns the draws of the frame into meshes. It runs in [`CoreSet::PostUpdate`], after transforms
+/// are propagated and before visibility is computed, so draws issued until then are rendered the
+/// same frame. Draws issued later, in [`CoreSet::Last`] for example, are rendered the next frame.
+///
+/// It used to be a base set of its own. It is now a regular set, since it runs inside
+/// [`CoreSet::PostUpdate`]: systems added with `.in_base_set(DebugDrawSystem)` should use
+/// `.in_set(DebugDrawSystem)` instead.
 #[derive(Debug, Hash, PartialEq, Eq, Clone, SystemSet)]
-#[system_set(base)]
 pub struct DebugDrawSystem;
 
 pub struct DebugDrawPlugin;
 
 impl Plugin for DebugDrawPlugin {
     fn build(&self, app: &mut App) {
-        app.init_resource::<DebugDraw>()
-            .configure_set(
-                DebugDrawSystem
-                    .after(CoreSet::Update)
-                    .before(CoreSet::UpdateFlush),
+        load_internal_asset!(
+            app,
+            DEBUG_DRAW_MATERIAL_SHADER_HANDLE,
+            "material.wgsl",
+            Shader::from_wgsl
+        );
+
+        configure_debug_draw_system(app);
+        app.add_plugin(Material2dPlugin::<DebugDrawMaterial>::default())
+            .init_resource::<DebugDraw>()
+            .init_resource::<DebugDrawMaterials>()
+            .init_resource::<DebugDrawerBuffers>()
+            .init_resource::<DebugTextFonts>()
+            .add_system(debug_text_fonts_loader)
+            .add_system(
+                debug_screen_camera
+                    .in_set(DebugDrawSystem)
+                    .before(debug_renderer),
             )
-            .add_system(debug_renderer.in_base_set(DebugDrawSystem));
+            .add_system(debug_draw_merge.in_set(DebugDrawSystem))
+            .add_system(
+                debug_renderer
+                    .in_set(DebugDrawSystem)
+                    .after(debug_draw_merge),
+            );
     }
 }
 
+/// Collects draws without rendering them, for headless apps. The meshes of the last frame are
+/// available from [`DebugDraw::rendered_meshes`]. Replaces [`DebugDrawPlugin`].
+pub struct DebugDrawHeadlessPlugin;
+
+impl Plugin for DebugDrawHeadlessPlugin {
+    fn build(&self, app: &mut App) {
+        configure_debug_draw_system(app);
+        app.init_resource::<DebugDraw>()
+            .init_resource::<DebugDrawerBuffers>()
+            .init_resource::<DebugTextFonts>()
+            .add_system(debug_text_fonts_loader)
+            .add_system(debug_draw_merge.in_set(DebugDrawSystem))
+            .add_system(
+                debug_headless_renderer
+                    .in_set(DebugDrawSystem)
+                    .after(debug_draw_merge),
+            );
+    }
+}
+
+/// Shared by every plugin of this crate, so each of them can be added on its own.
+fn configure_debug_draw_system(app: &mut App) {
+    if app.world.contains_resource::<DebugDrawSystemConfigured>() {
+        return;
+    }
+    app.init_resource::<DebugDrawSystemConfigured>()
+        .configure_set(
+            DebugDrawSystem
+                .in_base_set(CoreSet::PostUpdate)
+                .after(TransformSystem::TransformPropagate)
+                .before(VisibilitySystems::VisibilityPropagate)
+                .before(VisibilitySystems::CheckVisibility),
+        )
+        // Debug meshes spawned this frame must exist before visibility is computed.
+        .add_system(
+            apply_system_buffers
+                .in_base_set(CoreSet::PostUpdate)
+                .after(DebugDrawSystem)
+                .before(VisibilitySystems::VisibilityPropagate)
+                .before(VisibilitySystems::CheckVisibility),
+        );
+    configure_debug_draw_fixed_system(app);
+}
+
+#[derive(Resource, Default)]
+struct DebugDrawSystemConfigured;
+
 #[derive(Resource, Default)]
 pub struct DebugDraw {
-    meshes: Vec<DebugDrawMesh>,
+    calls: Vec<DebugDrawCall>,
+    instances: Vec<DebugDrawInstanceCall>,
+    instancing: bool,
+    target: DebugDrawTarget,
+    space: DebugDrawSpace,
+    timed: Vec<DebugDrawTimedCall>,
+    persistent: HashMap<DebugDrawHandle, DebugDrawCall>,
+    category: Option<DebugDrawCategory>,
+    categories: HashMap<DebugDrawCategory, DebugDrawCategorySettings>,
+    solo: Option<DebugDrawCategory>,
+    depth_mapping: DebugDrawDepthMapping,
+    blend_mode: DebugDrawBlendMode,
+    feathering: Option<f32>,
+    transform_stack: Vec<Affine2>,
+    rendered_calls: Vec<DebugDrawCall>,
+    fixed_tick: bool,
+    fixed_tick_count: u64,
+    fixed_calls: Vec<DebugDrawCall>,
+    fixed_instances: Vec<DebugDrawInstanceCall>,
+    channel: OnceLock<DebugDrawChannel>,
+    screen_layer: Option<u8>,
+    frozen: Option<(Vec<DebugDrawCall>, Vec<DebugDrawInstanceCall>)>,
+    /// The z of the instanced draws at each z, in front of the mesh runs there.
+    instance_z: BTreeMap<(RenderLayers, FloatOrd), f32>,
+    step_requested: bool,
+    freeze_transform: Affine2,
+    tessellation_time: Duration,
+    stats: DebugDrawStats,
 }
 
 impl DebugDraw {
     pub fn draw<T: DebugDrawDrawable>(&mut self, mesh: T) {
-        self.meshes.push(mesh.to_mesh());
+        if !self.is_category_enabled(self.category.as_ref()) {
+            return;
+        }
+        if self.instancing
+            && self.space == DebugDrawSpace::World
+            && self.blend_mode == DebugDrawBlendMode::Alpha
+            && self.feathering.is_none()
+            && self.transform_stack.is_empty()
+        {
+            if let Some(instance) = mesh.to_instance() {
+                let instance = (self.target, self.category.clone(), instance);
+                if self.fixed_tick {
+                    self.fixed_instances.push(instance);
+                } else {
+                    self.instances.push(instance);
+                }
+                return;
+            }
+        }
+        let call = self.make_call(&mesh);
+        if self.fixed_tick {
+            self.fixed_calls.push(call);
+        } else {
+            self.calls.push(call);
+        }
+    }
+
+    /// Draws a single drawable on the given target, regardless of the current target.
+    pub fn draw_on<T: DebugDrawDrawable>(&mut self, target: impl Into<DebugDrawTarget>, mesh: T) {
+        let previous_target = replace(&mut self.target, target.into());
+        self.draw(mesh);
+        self.target = previous_target;
+    }
+
+    /// Sets the target used by every following draw, until it is changed again.
+    pub fn set_target(&mut self, target: impl Into<DebugDrawTarget>) {
+        self.target = target.into();
+    }
+
+    pub fn target(&self) -> DebugDrawTarget {
+        self.target
+    }
+
+    /// Draws a single drawable in screen space, in logical pixels relative to the anchor.
+    pub fn draw_screen<T: DebugDrawDrawable>(&mut self, anchor: DebugDrawAnchor, mesh: T) {
+        let previous_space = replace(&mut self.space, DebugDrawSpace::Screen(anchor));
+        self.draw(mesh);
+        self.space = previous_space;
+    }
+
+    /// Sets the space used by every following draw, until it is changed again.
+    pub fn set_space(&mut self, space: DebugDrawSpace) {
+        self.space = space;
+    }
+
+    pub fn space(&self) -> DebugDrawSpace {
+        self.space
+    }
+
+    /// Keeps drawing a drawable for the given duration.
+    pub fn draw_for<T: DebugDrawDrawable>(&mut self, duration: DebugDrawDuration, mesh: T) {
+        if !self.is_category_enabled(self.category.as_ref()) {
+            return;
+        }
+        let call = self.make_call(&mesh);
+        self.timed
+            .push(DebugDrawTimedCall::new(call, duration, false));
+    }
+
+    /// Keeps drawing a drawable for the given duration, fading it out until it disappears.
+    pub fn draw_fading<T: DebugDrawDrawable>(&mut self, duration: DebugDrawDuration, mesh: T) {
+        if !self.is_category_enabled(self.category.as_ref()) {
+            return;
+        }
+        let call = self.make_call(&mesh);
+        self.timed
+            .push(DebugDrawTimedCall::new(call, duration, true));
+    }
+
+    /// Keeps drawing a drawable every frame until it is removed.
+    pub fn draw_persistent<T: DebugDrawDrawable>(&mut self, mesh: T) -> DebugDrawHandle {
+        let handle = DebugDrawHandle::next();
+        let call = self.make_call(&mesh);
+        self.persistent.insert(handle, call);
+        handle
+    }
+
+    /// Replaces the drawable of a persistent draw. Returns `false` if it was already removed.
+    pub fn update_persistent<T: DebugDrawDrawable>(
+        &mut self,
+        handle: DebugDrawHandle,
+        mesh: T,
+    ) -> bool {
+        let call = self.make_call(&mesh);
+        match self.persistent.get_mut(&handle) {
+            Some(persistent_call) => {
+                *persistent_call = call;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Stops a persistent draw. Returns `false` if it was already removed.
+    pub fn remove_persistent(&mut self, handle: DebugDrawHandle) -> bool {
+        self.persistent.remove(&handle).is_some()
+    }
+
+    /// Stops every timed and persistent draw.
+    pub fn clear_retained(&mut self) {
+        self.timed.clear();
+        self.persistent.clear();
+    }
+
+    fn make_call<T: DebugDrawDrawable>(&mut self, mesh: &T) -> DebugDrawCall {
+        let start = Instant::now();
+        let mut mesh = mesh.to_mesh();
+        self.tessellation_time += start.elapsed();
+        if !self.transform_stack.is_empty() {
+            mesh.transform(self.current_transform());
+        }
+        DebugDrawCall {
+            mesh,
+            target: self.target,
+            space: self.space,
+            category: self.category.clone(),
+            blend_mode: self.blend_mode,
+        }
+    }
+
+    /// The meshes rendered by the last frame, with their space, in drawing order.
+    pub fn rendered_meshes(&self) -> impl Iterator<Item = (&DebugDrawMesh, DebugDrawSpace)> {
+        self.rendered_calls
+            .iter()
+            .map(|call| (&call.mesh, call.space))
+    }
+
+    /// Adds the timed, persistent and latest fixed tick draws to this frame's calls.
+    fn flush_retained(&mut self, delta_seconds: f32) {
+        let calls = &mut self.calls;
+        self.timed
+            .retain_mut(|timed_call| match timed_call.tick(delta_seconds) {
+                Some(call) => {
+                    calls.push(call);
+                    true
+                }
+                None => false,
+            });
+        self.calls.extend(self.persistent.values().cloned());
+        self.calls.extend(self.fixed_calls.iter().cloned());
+        self.instances.extend(self.fixed_instances.iter().cloned());
+    }
+
+    fn has_screen_draws(&self) -> bool {
+        self.calls
+            .iter()
+            .chain(self.persistent.values())
+            .chain(self.timed.iter().map(|timed_call| &timed_call.call))
+            .any(|call| matches!(call.space, DebugDrawSpace::Screen(..)))
     }
 }
 
+/// An instanced draw, with the target and category it was drawn with.
+type DebugDrawInstanceCall = (
+    DebugDrawTarget,
+    Option<DebugDrawCategory>,
+    DebugDrawInstance,
+);
+
+#[derive(Clone)]
+struct DebugDrawCall {
+    mesh: DebugDrawMesh,
+    target: DebugDrawTarget,
+    space: DebugDrawSpace,
+    category: Option<DebugDrawCategory>,
+    blend_mode: DebugDrawBlendMode,
+}
+
 pub trait DebugDrawDrawable {
     fn to_mesh(&self) -> DebugDrawMesh;
+
+    /// Describes this drawable as a single GPU instance, if it can be expressed as one. Only used
+    /// when the [`DebugDrawInstancingPlugin`] is added.
+    fn to_instance(&self) -> Option<DebugDrawInstance> {
+        None
+    }
 }
 
 #[derive(Default, Debug, Clone)]
@@ -44,6 +339,8 @@ pub struct DebugDrawMesh {
     pub vertices: Vec<DebugDrawVertex>,
     pub indices: Vec<u32>,
     pub depth: f32,
+    /// Multiplied with the vertex colors, sampled at the vertex uvs.
+    pub texture: Option<Handle<Image>>,
 }
 
 impl DebugDrawDrawable for DebugDrawMesh {
@@ -57,7 +354,13 @@ impl DebugDrawMesh {
         Self::default()
     }
 
+    /// Appends the triangles of another mesh. An empty mesh takes the other's texture, otherwise
+    /// this mesh keeps its own. See [`DebugDrawMesh::try_merge_with`] to merge only meshes with
+    /// the same texture.
     pub fn merge_with(&mut self, other: &DebugDrawMesh) {
+        if self.vertices.is_empty() {
+            self.texture.clone_from(&other.texture);
+        }
         let base_index = self.vertices.len() as u32;
         self.vertices.extend(other.vertices.iter());
         self.indices.reserve(other.indices.len());
@@ -65,57 +368,390 @@ impl DebugDrawMesh {
             self.indices.push(base_index + *index);
         }
     }
+
+    /// Appends the triangles of another mesh if both use the same texture, or this mesh is empty.
+    /// Returns false and leaves this mesh unchanged otherwise, since a mesh has a single texture.
+    pub fn try_merge_with(&mut self, other: &DebugDrawMesh) -> bool {
+        if !self.vertices.is_empty() && self.texture != other.texture {
+            return false;
+        }
+        self.merge_with(other);
+        true
+    }
 }
 
 #[derive(Default, Debug, Clone, Copy)]
 pub struct DebugDrawVertex {
     pub position: Vec2,
     pub color: Color,
+    pub uv: Vec2,
 }
 
 #[derive(Component)]
-struct DebugDrawObject;
+struct DebugDrawObject {
+    key: DebugDrawBatchKey,
+}
+
+/// Draws are merged into one mesh per set of render layers, z, blend mode and texture. When
+/// those alternate at the same z, each run of draws gets its own mesh, slightly further in front
+/// than the previous one, to keep the depth order.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord)]
+struct DebugDrawBatchKey {
+    render_layers: RenderLayers,
+    z: FloatOrd,
+    run: u32,
+    material: DebugDrawMaterialId,
+}
+
+type DebugDrawMaterialId = (DebugDrawBlendMode, Option<Handle<Image>>);
 
+const DEBUG_DRAW_RUN_Z_STEP: f32 = 0.001;
+/// How far the runs of the highest z may spread, when there's no z above to stay under.
+const DEBUG_DRAW_RUN_Z_SPAN: f32 = 0.1;
+
+impl DebugDrawBatchKey {
+    fn z(&self, run_steps: &BTreeMap<(RenderLayers, FloatOrd), f32>) -> f32 {
+        run_z((self.render_layers, self.z), self.run, run_steps)
+    }
+}
+
+fn run_z(
+    key: (RenderLayers, FloatOrd),
+    run: u32,
+    run_steps: &BTreeMap<(RenderLayers, FloatOrd), f32>,
+) -> f32 {
+    let run_step = run_steps
+        .get(&key)
+        .copied()
+        .unwrap_or(DEBUG_DRAW_RUN_Z_STEP);
+    key.1 .0 + run as f32 * run_step
+}
+
+/// Adds a run for the instanced draws at each z, after the mesh runs there, and returns it.
+fn instance_runs(
+    runs: &mut BTreeMap<(RenderLayers, FloatOrd), (u32, DebugDrawMaterialId)>,
+    keys: impl IntoIterator<Item = (RenderLayers, FloatOrd)>,
+) -> BTreeMap<(RenderLayers, FloatOrd), u32> {
+    let mut instance_runs = BTreeMap::new();
+    for key in keys {
+        instance_runs
+            .entry(key)
+            .or_insert_with(|| match runs.get_mut(&key) {
+                Some((run, _)) => {
+                    *run += 1;
+                    *run
+                }
+                None => {
+                    runs.insert(key, (0, (DebugDrawBlendMode::Alpha, None)));
+                    0
+                }
+            });
+    }
+    instance_runs
+}
+
+/// The z offset between runs at each z. Runs spread over at most half the gap to the next z, so
+/// they never reach the draws above them.
+fn run_z_steps(
+    runs: &BTreeMap<(RenderLayers, FloatOrd), (u32, DebugDrawMaterialId)>,
+) -> BTreeMap<(RenderLayers, FloatOrd), f32> {
+    let zs = runs.keys().map(|(_, z)| *z).collect::<BTreeSet<_>>();
+    runs.iter()
+        .map(|(&(render_layers, z), (run, _))| {
+            let span = zs
+                .range((Bound::Excluded(z), Bound::Unbounded))
+                .next()
+                .map(|next| (next.0 - z.0) * 0.5)
+                .unwrap_or(DEBUG_DRAW_RUN_Z_SPAN);
+            let run_step = match *run {
+                0 => DEBUG_DRAW_RUN_Z_STEP,
+                run => DEBUG_DRAW_RUN_Z_STEP.min(span / run as f32),
+            };
+            ((render_layers, z), run_step)
+        })
+        .collect()
+}
+
+/// The materials of every blend mode and texture, with the number of frames since their texture
+/// was last drawn.
+#[derive(Resource, Default)]
+struct DebugDrawMaterials(HashMap<DebugDrawMaterialId, (Handle<DebugDrawMaterial>, u32)>);
+
+/// How many frames the material of a texture is kept after the texture stops being drawn, so
+/// textures drawn every few frames don't recreate their material each time.
+const DEBUG_DRAW_MATERIAL_UNUSED_FRAMES: u32 = 120;
+
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
 fn debug_renderer(
     mut commands: Commands,
     mut meshes: ResMut<Assets<Mesh>>,
-    mut materials: ResMut<Assets<ColorMaterial>>,
     mut debug_render: ResMut<DebugDraw>,
-    debug_query: Query<Entity, With<DebugDrawObject>>,
+    mut debug_materials: ResMut<DebugDrawMaterials>,
+    mut materials: ResMut<Assets<DebugDrawMaterial>>,
+    mut debug_query: Query<(
+        &mut DebugDrawObject,
+        &Mesh2dHandle,
+        &mut Handle<DebugDrawMaterial>,
+        &mut Transform,
+        &mut GlobalTransform,
+        &mut RenderLayers,
+        &mut Visibility,
+    )>,
+    camera_query: Query<Option<&RenderLayers>, (With<Camera>, Without<DebugDrawObject>)>,
+    projection_query: Query<
+        (
+            &Camera,
+            &OrthographicProjection,
+            &GlobalTransform,
+            Option<&RenderLayers>,
+        ),
+        Without<DebugDrawObject>,
+    >,
+    window_query: Query<&Window, With<PrimaryWindow>>,
+    time: Res<Time>,
 ) {
-    for debug_entity in debug_query.iter() {
-        commands.entity(debug_entity).despawn();
+    debug_render.begin_frame(time.delta_seconds());
+
+    let window_size = window_query
+        .get_single()
+        .map(|window| Vec2::new(window.width(), window.height()))
+        .unwrap_or_default();
+
+    debug_render.calls.sort_by(|a, b| {
+        a.mesh
+            .depth
+            .partial_cmp(&b.mesh.depth)
+            .unwrap_or(Ordering::Equal)
+    });
+
+    let upload_start = Instant::now();
+    let mut runs: BTreeMap<(RenderLayers, FloatOrd), (u32, DebugDrawMaterialId)> = BTreeMap::new();
+    let mut batches: BTreeMap<DebugDrawBatchKey, Vec<(Cow<DebugDrawMesh>, Vec2)>> = BTreeMap::new();
+    let mut pixel_sizes: BTreeMap<RenderLayers, f32> = BTreeMap::new();
+    for call in debug_render.calls.iter() {
+        let (render_layers, offset) = match call.space {
+            DebugDrawSpace::World => (
+                call.target
+                    .render_layers(&camera_query, debug_render.screen_layer()),
+                Vec2::ZERO,
+            ),
+            DebugDrawSpace::Screen(anchor) => (
+                RenderLayers::layer(debug_render.screen_layer()),
+                anchor.position(window_size),
+            ),
+        };
+        let mesh = match debug_render.feathering {
+            Some(width) => {
+                let pixel_size = *pixel_sizes
+                    .entry(render_layers)
+                    .or_insert_with(|| world_units_per_pixel(render_layers, &projection_query));
+                Cow::Owned(call.mesh.feathered(width * pixel_size))
+            }
+            None => Cow::Borrowed(&call.mesh),
+        };
+        let z = FloatOrd(debug_render.depth_mapping.z_for(call.mesh.depth));
+        let material = (call.blend_mode, call.mesh.texture.clone());
+        let (run, run_material) = runs
+            .entry((render_layers, z))
+            .or_insert_with(|| (0, material.clone()));
+        if *run_material != material {
+            *run += 1;
+            *run_material = material.clone();
+        }
+        batches
+            .entry(DebugDrawBatchKey {
+                render_layers,
+                z,
+                run: *run,
+                material,
+            })
+            .or_default()
+            .push((mesh, offset));
+    }
+
+    // Instanced draws are rendered in front of the meshes at the same z.
+    let instance_runs = instance_runs(
+        &mut runs,
+        debug_render.instances.iter().map(|(target, _, instance)| {
+            (
+                target.render_layers(&camera_query, debug_render.screen_layer()),
+                FloatOrd(debug_render.depth_mapping.z_for(instance.depth)),
+            )
+        }),
+    );
+    let run_steps = run_z_steps(&runs);
+    let instance_z = instance_runs
+        .into_iter()
+        .map(|(key, run)| (key, run_z(key, run, &run_steps)))
+        .collect();
+    let mut unused_objects = vec![];
+    for debug_object in debug_query.iter_mut() {
+        if let Some(batch) = batches.remove(&debug_object.0.key) {
+            let (
+                debug_object,
+                debug_mesh_handle,
+                _,
+                mut transform,
+                mut global_transform,
+                _,
+                mut debug_visibility,
+            ) = debug_object;
+            if let Some(mesh) = meshes.get_mut(&debug_mesh_handle.0) {
+                write_mesh(mesh, &batch);
+            }
+            let z = debug_object.key.z(&run_steps);
+            // Transforms were already propagated this frame, and debug meshes have no parent.
+            if transform.translation.z != z {
+                transform.translation.z = z;
+                *global_transform = GlobalTransform::from(*transform);
+            }
+            *debug_visibility = Visibility::Inherited;
+        } else {
+            unused_objects.push(debug_object);
+        }
+    }
+
+    // Unused objects are moved to the remaining batches before spawning new ones, so the pool
+    // doesn't grow with every distinct z that was ever drawn at.
+    for (key, batch) in batches.into_iter() {
+        let material = debug_materials
+            .0
+            .entry(key.material.clone())
+            .or_insert_with(|| {
+                let material = materials.add(DebugDrawMaterial {
+                    blend_mode: key.material.0,
+                    texture: key.material.1.clone(),
+                });
+                (material, 0)
+            })
+            .0
+            .clone();
+        if let Some(debug_object) = unused_objects.pop() {
+            let (
+                mut debug_object,
+                debug_mesh_handle,
+                mut debug_material,
+                mut transform,
+                mut global_transform,
+                mut render_layers,
+                mut debug_visibility,
+            ) = debug_object;
+            if let Some(mesh) = meshes.get_mut(&debug_mesh_handle.0) {
+                write_mesh(mesh, &batch);
+            }
+            transform.translation.z = key.z(&run_steps);
+            *global_transform = GlobalTransform::from(*transform);
+            *render_layers = key.render_layers;
+            debug_object.key = key;
+            *debug_material = material;
+            *debug_visibility = Visibility::Inherited;
+            continue;
+        }
+        let mut mesh = Mesh::new(PrimitiveTopology::TriangleList);
+        write_mesh(&mut mesh, &batch);
+        let transform = Transform::from_xyz(0., 0., key.z(&run_steps));
+        commands
+            .spawn(MaterialMesh2dBundle {
+                mesh: Mesh2dHandle(meshes.add(mesh)),
+                material,
+                transform,
+                global_transform: GlobalTransform::from(transform),
+                ..Default::default()
+            })
+            .insert((key.render_layers, DebugDrawObject { key }));
     }
 
-    let mut merged_mesh = DebugDrawMesh::new();
-    debug_render
-        .meshes
-        .sort_by(|a, b| a.depth.partial_cmp(&b.depth).unwrap_or(Ordering::Equal));
-    for debug_render_mesh in take(&mut debug_render.meshes).into_iter() {
-        merged_mesh.merge_with(&debug_render_mesh);
+    for (_, _, _, _, _, _, mut debug_visibility) in unused_objects {
+        *debug_visibility = Visibility::Hidden;
     }
 
-    let DebugDrawMesh {
-        vertices, indices, ..
-    } = merged_mesh;
+    // Materials of textures that weren't drawn for a while are dropped, so short-lived images
+    // don't accumulate. Pooled objects keep theirs alive until they are moved to another batch.
+    let textures = debug_render
+        .calls
+        .iter()
+        .filter_map(|call| call.mesh.texture.as_ref())
+        .collect::<HashSet<_>>();
+    debug_materials
+        .0
+        .retain(|(_, texture), (_, unused_frames)| match texture {
+            None => true,
+            Some(texture) if textures.contains(texture) => {
+                *unused_frames = 0;
+                true
+            }
+            Some(_) => {
+                *unused_frames += 1;
+                *unused_frames <= DEBUG_DRAW_MATERIAL_UNUSED_FRAMES
+            }
+        });
+
+    // The calls become the front buffer, and the previous one is reused for the next frame.
+    let debug_render = debug_render.as_mut();
+    debug_render.instance_z = instance_z;
+    debug_render.update_stats(upload_start.elapsed());
+    swap(&mut debug_render.calls, &mut debug_render.rendered_calls);
+    debug_render.calls.clear();
+}
 
-    let mut mesh = Mesh::new(PrimitiveTopology::TriangleList);
+fn debug_headless_renderer(mut debug_render: ResMut<DebugDraw>, time: Res<Time>) {
+    let debug_render = debug_render.as_mut();
+    debug_render.begin_frame(time.delta_seconds());
+    debug_render.calls.sort_by(|a, b| {
+        a.mesh
+            .depth
+            .partial_cmp(&b.mesh.depth)
+            .unwrap_or(Ordering::Equal)
+    });
+    debug_render.update_stats(Duration::ZERO);
+    swap(&mut debug_render.calls, &mut debug_render.rendered_calls);
+    debug_render.calls.clear();
+}
 
-    let mut positions: Vec<[f32; 3]> = vec![];
-    let mut normals: Vec<[f32; 3]> = vec![];
-    let mut uvs: Vec<[f32; 2]> = vec![];
-    let mut colors: Vec<[f32; 4]> = vec![];
+/// Rewrites the attributes of a retained mesh in place, reusing the existing buffers.
+fn write_mesh(mesh: &mut Mesh, debug_render_meshes: &[(Cow<DebugDrawMesh>, Vec2)]) {
+    let mut positions = reuse_attribute(mesh, Mesh::ATTRIBUTE_POSITION, |values| match values {
+        VertexAttributeValues::Float32x3(values) => Some(values),
+        _ => None,
+    });
+    let mut normals = reuse_attribute(mesh, Mesh::ATTRIBUTE_NORMAL, |values| match values {
+        VertexAttributeValues::Float32x3(values) => Some(values),
+        _ => None,
+    });
+    let mut uvs = reuse_attribute(mesh, Mesh::ATTRIBUTE_UV_0, |values| match values {
+        VertexAttributeValues::Float32x2(values) => Some(values),
+        _ => None,
+    });
+    let mut colors = reuse_attribute(mesh, Mesh::ATTRIBUTE_COLOR, |values| match values {
+        VertexAttributeValues::Float32x4(values) => Some(values),
+        _ => None,
+    });
+    let mut indices = match mesh.indices_mut() {
+        Some(Indices::U32(indices)) => take(indices),
+        _ => vec![],
+    };
+    indices.clear();
 
-    for vertex in vertices.iter() {
-        positions.push([vertex.position.x, vertex.position.y, 1.]);
-        normals.push([0., 0., 0.]);
-        uvs.push([0., 0.]);
-        colors.push([
-            vertex.color.r(),
-            vertex.color.g(),
-            vertex.color.b(),
-            vertex.color.a(),
-        ]);
+    for (debug_render_mesh, offset) in debug_render_meshes.iter() {
+        let base_index = positions.len() as u32;
+        for vertex in debug_render_mesh.vertices.iter() {
+            let position = vertex.position + *offset;
+            positions.push([position.x, position.y, 0.]);
+            normals.push([0., 0., 0.]);
+            uvs.push(vertex.uv.into());
+            colors.push([
+                vertex.color.r(),
+                vertex.color.g(),
+                vertex.color.b(),
+                vertex.color.a(),
+            ]);
+        }
+        indices.extend(
+            debug_render_mesh
+                .indices
+                .iter()
+                .map(|index| base_index + *index),
+        );
     }
 
     mesh.set_indices(Some(Indices::U32(indices)));
@@ -123,30 +759,195 @@ fn debug_renderer(
     mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, normals);
     mesh.insert_attribute(Mesh::ATTRIBUTE_UV_0, uvs);
     mesh.insert_attribute(Mesh::ATTRIBUTE_COLOR, colors);
+}
 
-    commands
-        .spawn(ColorMesh2dBundle {
-            mesh: Mesh2dHandle(meshes.add(mesh)),
-            material: materials.add(ColorMaterial {
-                color: Color::WHITE,
-                texture: None,
-            }),
-            transform: Transform::from_xyz(0., 0., 1.),
-            ..Default::default()
-        })
-        .insert(DebugDrawObject);
+pub(crate) fn reuse_attribute<T>(
+    mesh: &mut Mesh,
+    attribute: MeshVertexAttribute,
+    unwrap: impl FnOnce(VertexAttributeValues) -> Option<Vec<T>>,
+) -> Vec<T> {
+    let mut values = mesh
+        .remove_attribute(attribute)
+        .and_then(unwrap)
+        .unwrap_or_default();
+    values.clear();
+    values
 }
 
+mod arrow_3d;
+mod blend;
+mod box_3d;
+mod category;
 mod circle;
+mod cylinder;
+mod depth;
+mod diagnostics;
+mod draw_3d;
+mod drawer;
+mod feather;
+mod fixed;
+mod font;
+mod freeze;
+mod image;
+mod instancing;
 mod line;
+mod line_3d;
+mod material;
+mod persistent;
+#[cfg(feature = "raster")]
+mod raster;
+#[cfg(feature = "recording")]
+mod recording;
 mod rectangle;
+#[cfg(feature = "remote")]
+mod remote;
+mod screen;
+mod sender;
+mod sphere;
+mod svg;
+mod target;
 mod text;
+mod text_3d;
+mod transform;
 mod triangle;
 
+pub use arrow_3d::*;
+pub use blend::*;
+pub use box_3d::*;
+pub use category::*;
 pub use circle::*;
+pub use cylinder::*;
+pub use depth::*;
+pub use diagnostics::*;
+pub use draw_3d::*;
+pub use drawer::*;
+pub(crate) use feather::*;
+pub use fixed::*;
+pub use font::*;
+pub use freeze::*;
+pub use image::*;
+pub use instancing::*;
 pub use line::*;
+pub use line_3d::*;
+pub(crate) use material::*;
+pub use persistent::*;
+#[cfg(feature = "raster")]
+pub use raster::*;
+#[cfg(feature = "recording")]
+pub use recording::*;
 pub use rectangle::*;
+#[cfg(feature = "remote")]
+pub use remote::*;
+pub use screen::*;
+pub use sender::*;
+pub use sphere::*;
+pub use svg::*;
+pub use target::*;
 pub use text::*;
+pub use text_3d::*;
+pub use transform::*;
 pub use triangle::*;
 
 pub mod prelude;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn triangle(texture: Option<Handle<Image>>) -> DebugDrawMesh {
+        DebugDrawMesh {
+            vertices: vec![DebugDrawVertex::default(); 3],
+            indices: vec![0, 1, 2],
+            texture,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn merges_only_meshes_with_the_same_texture() {
+        let texture = Handle::<Image>::weak(bevy::asset::HandleId::random::<Image>());
+        let mut mesh = DebugDrawMesh::new();
+        assert!(mesh.try_merge_with(&triangle(Some(texture.clone()))));
+        assert!(mesh.try_merge_with(&triangle(Some(texture.clone()))));
+        assert_eq!(mesh.indices, [0, 1, 2, 3, 4, 5]);
+        assert!(!mesh.try_merge_with(&triangle(None)));
+        assert_eq!(mesh.vertices.len(), 6);
+
+        let mut untextured = triangle(None);
+        untextured.merge_with(&triangle(Some(texture)));
+        assert_eq!(untextured.vertices.len(), 6);
+        assert_eq!(untextured.texture, None);
+    }
+
+    #[test]
+    fn debug_meshes_have_their_global_transform_the_frame_they_are_drawn() {
+        #[derive(Resource)]
+        struct Depth(f32);
+
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_plugin(AssetPlugin::default())
+            .add_asset::<Shader>()
+            .add_asset::<Mesh>()
+            .add_asset::<Image>()
+            .add_plugin(DebugDrawPlugin)
+            .insert_resource(Depth(5.))
+            .add_system(|mut debug_draw: ResMut<DebugDraw>, depth: Res<Depth>| {
+                debug_draw.draw(DebugDrawMesh {
+                    depth: depth.0,
+                    ..triangle(None)
+                });
+            });
+        let global_z = |app: &mut App| {
+            app.world
+                .query_filtered::<&GlobalTransform, With<DebugDrawObject>>()
+                .single(&app.world)
+                .translation()
+                .z
+        };
+
+        app.world
+            .resource_mut::<DebugDraw>()
+            .set_depth_mapping(DebugDrawDepthMapping::z());
+
+        app.update();
+        assert_eq!(global_z(&mut app), 5.);
+        // Pooled objects are moved to the z of the new draws.
+        app.world.resource_mut::<Depth>().0 = 2.;
+        app.update();
+        assert_eq!(global_z(&mut app), 2.);
+    }
+
+    #[test]
+    fn instances_go_in_front_of_the_mesh_runs_of_their_z() {
+        let layers = RenderLayers::default();
+        let mut runs = BTreeMap::new();
+        runs.insert(
+            (layers, FloatOrd(0.)),
+            (1, (DebugDrawBlendMode::Additive, None)),
+        );
+        runs.insert(
+            (layers, FloatOrd(1.)),
+            (0, (DebugDrawBlendMode::Alpha, None)),
+        );
+        let instance_runs =
+            instance_runs(&mut runs, [(layers, FloatOrd(0.)), (layers, FloatOrd(2.))]);
+        let run_steps = run_z_steps(&runs);
+
+        let last_mesh_z = run_z((layers, FloatOrd(0.)), 1, &run_steps);
+        let instance_z = run_z(
+            (layers, FloatOrd(0.)),
+            instance_runs[&(layers, FloatOrd(0.))],
+            &run_steps,
+        );
+        assert!(instance_z > last_mesh_z);
+        assert!(instance_z < 1.);
+        // Without meshes at their z, instances stay at it.
+        let instance_z = run_z(
+            (layers, FloatOrd(2.)),
+            instance_runs[&(layers, FloatOrd(2.))],
+            &run_steps,
+        );
+        assert_eq!(instance_z, 2.);
+    }
+}
@@ -1,6 +1,6 @@
 use bevy::prelude::*;
 
-use crate::{DebugDrawDrawable, DebugDrawMesh, DebugDrawVertex};
+use crate::{DebugDrawDrawable, DebugDrawInstance, DebugDrawMesh, DebugDrawVertex};
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct DebugLine {
@@ -56,23 +56,45 @@ impl DebugDrawDrawable for DebugLine {
                     DebugDrawVertex {
                         position: self.from - orthogonal,
                         color: from_color,
+                        ..Default::default()
                     },
                     DebugDrawVertex {
                         position: self.from + orthogonal,
                         color: from_color,
+                        ..Default::default()
                     },
                     DebugDrawVertex {
                         position: self.to - orthogonal,
                         color: to_color,
+                        ..Default::default()
                     },
                     DebugDrawVertex {
                         position: self.to + orthogonal,
                         color: to_color,
+                        ..Default::default()
                     },
                 ],
                 indices: vec![0, 1, 2, 3, 2, 1],
                 depth: self.depth,
+                ..Default::default()
             }
         }
     }
+
+    fn to_instance(&self) -> Option<DebugDrawInstance> {
+        let (start_color, end_color) = match self.color {
+            DebugLineColor::Solid(color) => (color, color),
+            DebugLineColor::Gradient(from_color, to_color) => (from_color, to_color),
+        };
+        let difference = self.to - self.from;
+        Some(DebugDrawInstance {
+            center: (self.from + self.to) * 0.5,
+            size: Vec2::new(difference.length(), self.thickness),
+            rotation: difference.y.atan2(difference.x),
+            segments: 0,
+            start_color,
+            end_color,
+            depth: self.depth,
+        })
+    }
 }
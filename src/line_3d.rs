@@ -0,0 +1,34 @@
+use bevy::prelude::*;
+
+use crate::{DebugDrawDrawable3d, DebugDrawMesh3d, DebugLineColor};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DebugLine3d {
+    pub from: Vec3,
+    pub to: Vec3,
+    pub color: DebugLineColor,
+    pub thickness: f32,
+}
+
+impl Default for DebugLine3d {
+    fn default() -> Self {
+        Self {
+            from: Vec3::ZERO,
+            to: Vec3::ZERO,
+            color: DebugLineColor::Solid(Color::BLACK),
+            thickness: 0.02,
+        }
+    }
+}
+
+impl DebugDrawDrawable3d for DebugLine3d {
+    fn to_mesh_3d(&self) -> DebugDrawMesh3d {
+        let (from_color, to_color) = match self.color {
+            DebugLineColor::Solid(color) => (color, color),
+            DebugLineColor::Gradient(from_color, to_color) => (from_color, to_color),
+        };
+        let mut mesh = DebugDrawMesh3d::new();
+        mesh.add_segment(self.from, self.to, self.thickness, from_color, to_color);
+        mesh
+    }
+}
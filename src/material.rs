@@ -0,0 +1,73 @@
+use bevy::{
+    prelude::*,
+    reflect::TypeUuid,
+    render::{
+        mesh::MeshVertexBufferLayout,
+        render_resource::{
+            AsBindGroup, RenderPipelineDescriptor, ShaderRef, SpecializedMeshPipelineError,
+        },
+    },
+    sprite::{Material2d, Material2dKey},
+};
+
+use crate::DebugDrawBlendMode;
+
+pub(crate) const DEBUG_DRAW_MATERIAL_SHADER_HANDLE: HandleUntyped =
+    HandleUntyped::weak_from_u64(Shader::TYPE_UUID, 11708454213960317283);
+
+/// The material of the 2D debug meshes. There is one per blend mode and texture in use.
+#[derive(AsBindGroup, TypeUuid, Clone)]
+#[uuid = "8e0f3b6a-1f4d-4a4c-9d2e-6b7c5a3e1d90"]
+#[bind_group_data(DebugDrawMaterialKey)]
+pub(crate) struct DebugDrawMaterial {
+    pub(crate) blend_mode: DebugDrawBlendMode,
+    #[texture(0)]
+    #[sampler(1)]
+    pub(crate) texture: Option<Handle<Image>>,
+}
+
+#[derive(Clone, Copy, Hash, PartialEq, Eq)]
+pub(crate) struct DebugDrawMaterialKey {
+    blend_mode: DebugDrawBlendMode,
+    textured: bool,
+}
+
+impl From<&DebugDrawMaterial> for DebugDrawMaterialKey {
+    fn from(material: &DebugDrawMaterial) -> Self {
+        Self {
+            blend_mode: material.blend_mode,
+            textured: material.texture.is_some(),
+        }
+    }
+}
+
+impl Material2d for DebugDrawMaterial {
+    fn fragment_shader() -> ShaderRef {
+        DEBUG_DRAW_MATERIAL_SHADER_HANDLE.typed().into()
+    }
+
+    fn specialize(
+        descriptor: &mut RenderPipelineDescriptor,
+        _layout: &MeshVertexBufferLayout,
+        key: Material2dKey<Self>,
+    ) -> Result<(), SpecializedMeshPipelineError> {
+        let Some(fragment) = descriptor.fragment.as_mut() else {
+            return Ok(());
+        };
+        let key = key.bind_group_data;
+        if key.textured {
+            fragment.shader_defs.push("DEBUG_DRAW_TEXTURED".into());
+        }
+        match key.blend_mode {
+            DebugDrawBlendMode::Multiply => {
+                fragment.shader_defs.push("DEBUG_DRAW_PREMULTIPLY".into())
+            }
+            DebugDrawBlendMode::Invert => fragment.shader_defs.push("DEBUG_DRAW_INVERT".into()),
+            _ => {}
+        }
+        for target in fragment.targets.iter_mut().flatten() {
+            target.blend = Some(key.blend_mode.blend_state());
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,37 @@
+#import bevy_sprite::mesh2d_types
+#import bevy_sprite::mesh2d_view_bindings
+
+#ifdef TONEMAP_IN_SHADER
+#import bevy_core_pipeline::tonemapping
+#endif
+
+@group(1) @binding(0)
+var texture: texture_2d<f32>;
+@group(1) @binding(1)
+var texture_sampler: sampler;
+
+struct FragmentInput {
+    #import bevy_sprite::mesh2d_vertex_output
+};
+
+@fragment
+fn fragment(in: FragmentInput) -> @location(0) vec4<f32> {
+    var output_color: vec4<f32> = vec4<f32>(1.0);
+#ifdef VERTEX_COLORS
+    output_color = in.color;
+#endif
+#ifdef DEBUG_DRAW_TEXTURED
+    output_color = output_color * textureSample(texture, texture_sampler, in.uv);
+#endif
+#ifdef TONEMAP_IN_SHADER
+    output_color = tone_mapping(output_color);
+#endif
+#ifdef DEBUG_DRAW_PREMULTIPLY
+    output_color = vec4<f32>(output_color.rgb * output_color.a, output_color.a);
+#endif
+#ifdef DEBUG_DRAW_INVERT
+    // Blended with one minus the destination, so only the coverage matters.
+    output_color = vec4<f32>(output_color.a);
+#endif
+    return output_color;
+}
@@ -0,0 +1,70 @@
+use std::sync::atomic::{AtomicU64, Ordering as AtomicOrdering};
+
+use crate::DebugDrawCall;
+
+/// How long a timed draw stays on screen.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DebugDrawDuration {
+    Seconds(f32),
+    Frames(u32),
+}
+
+impl DebugDrawDuration {
+    fn amount(&self) -> f32 {
+        match *self {
+            DebugDrawDuration::Seconds(seconds) => seconds,
+            DebugDrawDuration::Frames(frames) => frames as f32,
+        }
+    }
+}
+
+/// Identifies a persistent draw, so it can be updated or removed later.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct DebugDrawHandle(pub(crate) u64);
+
+impl DebugDrawHandle {
+    /// Handles are unique across [`DebugDraw`](crate::DebugDraw) and every
+    /// [`DebugDrawer`](crate::DebugDrawer) buffer.
+    pub(crate) fn next() -> Self {
+        static NEXT_HANDLE: AtomicU64 = AtomicU64::new(0);
+        Self(NEXT_HANDLE.fetch_add(1, AtomicOrdering::Relaxed))
+    }
+}
+
+pub(crate) struct DebugDrawTimedCall {
+    pub(crate) call: DebugDrawCall,
+    pub(crate) duration: DebugDrawDuration,
+    pub(crate) remaining: f32,
+    pub(crate) fade: bool,
+}
+
+impl DebugDrawTimedCall {
+    pub(crate) fn new(call: DebugDrawCall, duration: DebugDrawDuration, fade: bool) -> Self {
+        Self {
+            call,
+            duration,
+            remaining: duration.amount(),
+            fade,
+        }
+    }
+
+    /// Returns the call to render this frame, or `None` once it has expired.
+    pub(crate) fn tick(&mut self, delta_seconds: f32) -> Option<DebugDrawCall> {
+        if self.remaining <= 0. {
+            return None;
+        }
+        let mut call = self.call.clone();
+        if self.fade {
+            let alpha = self.remaining / self.duration.amount();
+            for vertex in call.mesh.vertices.iter_mut() {
+                let color_alpha = vertex.color.a();
+                vertex.color.set_a(color_alpha * alpha);
+            }
+        }
+        self.remaining -= match self.duration {
+            DebugDrawDuration::Seconds(_) => delta_seconds,
+            DebugDrawDuration::Frames(_) => 1.,
+        };
+        Some(call)
+    }
+}
@@ -1,4 +1,22 @@
 pub use crate::{
-    DebugCircle, DebugDraw, DebugDrawMesh, DebugDrawPlugin, DebugDrawVertex, DebugLine,
-    DebugRectangle, DebugText, DebugTriangle,
+    DebugArrow3d, DebugBox, DebugCircle, DebugCylinder, DebugDraw, DebugDraw3d, DebugDraw3dPlugin,
+    DebugDrawAnchor, DebugDrawBlendMode, DebugDrawCategory, DebugDrawDepthMapping,
+    DebugDrawDiagnosticsPlugin, DebugDrawDuration, DebugDrawFixedSystem, DebugDrawFreezeControls,
+    DebugDrawFreezePlugin, DebugDrawHandle, DebugDrawHeadlessPlugin, DebugDrawInstancingPlugin,
+    DebugDrawMesh, DebugDrawPlugin, DebugDrawSender, DebugDrawSvg, DebugDrawSvgHotkey,
+    DebugDrawSvgPlugin, DebugDrawSystem, DebugDrawTarget, DebugDrawTransform, DebugDrawVertex,
+    DebugDrawer, DebugImage, DebugLine, DebugLine3d, DebugRectangle, DebugSphere, DebugText,
+    DebugText3d, DebugTextFont, DebugTextFonts, DebugTriangle,
+};
+
+#[cfg(feature = "raster")]
+pub use crate::{DebugDrawPixels, DebugDrawRasterizer};
+
+#[cfg(feature = "recording")]
+pub use crate::{DebugDrawRecorder, DebugDrawRecorderPlugin};
+
+#[cfg(feature = "remote")]
+pub use crate::{
+    DebugDrawLoopback, DebugDrawRemote, DebugDrawRemoteEndpoint, DebugDrawRemoteSourcePlugin,
+    DebugDrawRemoteViewerPlugin,
 };
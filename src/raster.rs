@@ -0,0 +1,257 @@
+use std::{cmp::Ordering, path::Path};
+
+use ::image::{
+    error::{LimitError, LimitErrorKind},
+    ImageError, ImageResult,
+};
+use bevy::prelude::*;
+
+use crate::{DebugDraw, DebugDrawBlendMode, DebugDrawMesh, DebugDrawSpace};
+
+/// Renders debug meshes on the CPU, for environments without a GPU such as CI. Meshes are
+/// blended in depth order, with the same blend modes as on the GPU. Textures are ignored, and
+/// instanced draws are skipped, since they are only turned into shapes on the GPU. Requires the
+/// `raster` feature.
+#[derive(Clone, Debug)]
+pub struct DebugDrawRasterizer {
+    /// The area of the world shown by the image.
+    pub view: Rect,
+    pub width: u32,
+    pub height: u32,
+    pub background: Color,
+}
+
+impl Default for DebugDrawRasterizer {
+    fn default() -> Self {
+        Self {
+            view: Rect::new(-640., -360., 640., 360.),
+            width: 1280,
+            height: 720,
+            background: Color::NONE,
+        }
+    }
+}
+
+impl DebugDrawRasterizer {
+    /// Alpha blends the meshes. Fails if the image is too large to allocate.
+    pub fn rasterize<'a>(
+        &self,
+        meshes: impl IntoIterator<Item = &'a DebugDrawMesh>,
+    ) -> ImageResult<DebugDrawPixels> {
+        self.rasterize_blended(
+            meshes
+                .into_iter()
+                .map(|mesh| (mesh, DebugDrawBlendMode::Alpha)),
+        )
+    }
+
+    /// Blends every mesh with its own blend mode. Fails if the image is too large to allocate.
+    pub fn rasterize_blended<'a>(
+        &self,
+        meshes: impl IntoIterator<Item = (&'a DebugDrawMesh, DebugDrawBlendMode)>,
+    ) -> ImageResult<DebugDrawPixels> {
+        let pixel_count = (self.width as usize)
+            .checked_mul(self.height as usize)
+            .filter(|pixel_count| pixel_count.checked_mul(4).is_some())
+            .ok_or_else(|| {
+                ImageError::Limits(LimitError::from_kind(LimitErrorKind::DimensionError))
+            })?;
+        let mut meshes = meshes.into_iter().collect::<Vec<_>>();
+        meshes.sort_by(|(a, _), (b, _)| a.depth.partial_cmp(&b.depth).unwrap_or(Ordering::Equal));
+
+        let background = Vec4::from(self.background.as_rgba_f32());
+        let mut pixels = try_vec(pixel_count)?;
+        pixels.resize(pixel_count, background);
+        for (mesh, blend_mode) in meshes {
+            for triangle in mesh.indices.chunks_exact(3) {
+                let vertices = [
+                    &mesh.vertices[triangle[0] as usize],
+                    &mesh.vertices[triangle[1] as usize],
+                    &mesh.vertices[triangle[2] as usize],
+                ];
+                self.rasterize_triangle(
+                    &mut pixels,
+                    vertices.map(|vertex| self.to_pixel(vertex.position)),
+                    vertices.map(|vertex| Vec4::from(vertex.color.as_rgba_f32())),
+                    blend_mode,
+                );
+            }
+        }
+
+        let mut data = try_vec(pixel_count * 4)?;
+        data.extend(pixels.iter().flat_map(|pixel| {
+            pixel
+                .to_array()
+                .map(|channel| (channel.clamp(0., 1.) * 255.).round() as u8)
+        }));
+        Ok(DebugDrawPixels {
+            width: self.width,
+            height: self.height,
+            data,
+        })
+    }
+
+    /// Rasterizes the world space meshes rendered by the last frame.
+    pub fn rasterize_debug_draw(&self, debug_draw: &DebugDraw) -> ImageResult<DebugDrawPixels> {
+        self.rasterize_blended(
+            debug_draw
+                .rendered_calls
+                .iter()
+                .filter(|call| call.space == DebugDrawSpace::World)
+                .map(|call| (&call.mesh, call.blend_mode)),
+        )
+    }
+
+    /// Maps a world position to continuous pixel coordinates, with y down.
+    fn to_pixel(&self, position: Vec2) -> Vec2 {
+        Vec2::new(
+            (position.x - self.view.min.x) / self.view.width() * self.width as f32,
+            (self.view.max.y - position.y) / self.view.height() * self.height as f32,
+        )
+    }
+
+    fn rasterize_triangle(
+        &self,
+        pixels: &mut [Vec4],
+        positions: [Vec2; 3],
+        colors: [Vec4; 3],
+        blend_mode: DebugDrawBlendMode,
+    ) {
+        let area = edge(positions[0], positions[1], positions[2]);
+        if area == 0. || !area.is_finite() {
+            return;
+        }
+        let (positions, colors) = if area < 0. {
+            (
+                [positions[0], positions[2], positions[1]],
+                [colors[0], colors[2], colors[1]],
+            )
+        } else {
+            (positions, colors)
+        };
+        let area = area.abs();
+
+        let min = positions[0].min(positions[1]).min(positions[2]).floor();
+        let max = positions[0].max(positions[1]).max(positions[2]).ceil();
+        let (min_x, min_y) = (min.x.max(0.) as u32, min.y.max(0.) as u32);
+        let (max_x, max_y) = (
+            (max.x as u32).min(self.width),
+            (max.y as u32).min(self.height),
+        );
+
+        // Each edge is the one facing the vertex of the same index.
+        let edges = [
+            (positions[1], positions[2]),
+            (positions[2], positions[0]),
+            (positions[0], positions[1]),
+        ];
+        for y in min_y..max_y {
+            for x in min_x..max_x {
+                let point = Vec2::new(x as f32 + 0.5, y as f32 + 0.5);
+                let weights = edges.map(|(from, to)| edge(from, to, point));
+                // Pixels exactly on an edge shared by two triangles belong to only one of them,
+                // so they aren't blended twice.
+                let inside = edges.iter().zip(weights).all(|((from, to), weight)| {
+                    let direction = *to - *from;
+                    weight > 0.
+                        || (weight == 0.
+                            && (direction.y > 0. || (direction.y == 0. && direction.x < 0.)))
+                });
+                if !inside {
+                    continue;
+                }
+                let color =
+                    (colors[0] * weights[0] + colors[1] * weights[1] + colors[2] * weights[2])
+                        / area;
+                let pixel = &mut pixels[y as usize * self.width as usize + x as usize];
+                *pixel = blend(blend_mode, color, *pixel);
+            }
+        }
+    }
+}
+
+/// Blends straight alpha colors like the blend states of [`DebugDrawBlendMode`], with the alpha
+/// combined by the "over" operator.
+fn blend(blend_mode: DebugDrawBlendMode, source: Vec4, destination: Vec4) -> Vec4 {
+    let alpha = source.w + destination.w * (1. - source.w);
+    if alpha <= 0. {
+        return Vec4::ZERO;
+    }
+    // Premultiplied, so a translucent destination blends like an opaque one over the background.
+    let color = source.truncate();
+    let behind = destination.truncate() * destination.w;
+    let blended = match blend_mode {
+        DebugDrawBlendMode::Alpha => color * source.w + behind * (1. - source.w),
+        DebugDrawBlendMode::Additive => color * source.w + behind,
+        DebugDrawBlendMode::Multiply => behind * color * source.w + behind * (1. - source.w),
+        DebugDrawBlendMode::Invert => {
+            (Vec3::splat(destination.w) - behind) * source.w + behind * (1. - source.w)
+        }
+    };
+    (blended / alpha).extend(alpha)
+}
+
+/// An empty vector with room for `capacity` items, or an error instead of aborting when the
+/// memory can't be allocated.
+fn try_vec<T>(capacity: usize) -> ImageResult<Vec<T>> {
+    let mut vec = Vec::new();
+    vec.try_reserve_exact(capacity).map_err(|_| {
+        ImageError::Limits(LimitError::from_kind(LimitErrorKind::InsufficientMemory))
+    })?;
+    Ok(vec)
+}
+
+fn edge(from: Vec2, to: Vec2, point: Vec2) -> f32 {
+    (to.x - from.x) * (point.y - from.y) - (to.y - from.y) * (point.x - from.x)
+}
+
+/// An RGBA image with 8 bits per channel, in rows from top to bottom.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DebugDrawPixels {
+    pub width: u32,
+    pub height: u32,
+    pub data: Vec<u8>,
+}
+
+impl DebugDrawPixels {
+    pub fn get(&self, x: u32, y: u32) -> [u8; 4] {
+        let index = (y as usize * self.width as usize + x as usize) * 4;
+        [
+            self.data[index],
+            self.data[index + 1],
+            self.data[index + 2],
+            self.data[index + 3],
+        ]
+    }
+
+    pub fn save_png(&self, path: impl AsRef<Path>) -> ::image::ImageResult<()> {
+        ::image::save_buffer(
+            path,
+            &self.data,
+            self.width,
+            self.height,
+            ::image::ColorType::Rgba8,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fails_to_allocate_huge_images() {
+        // The pixel count fits in memory addresses, but not its colors.
+        let rasterizer = DebugDrawRasterizer {
+            width: 1 << 30,
+            height: 1 << 30,
+            ..Default::default()
+        };
+        match rasterizer.rasterize([]) {
+            Err(ImageError::Limits(error)) => {
+                assert_eq!(error.kind(), LimitErrorKind::InsufficientMemory)
+            }
+            result => panic!("expected a limit error, got {:?}", result.map(|_| ())),
+        }
+    }
+}
@@ -0,0 +1,206 @@
+use std::{
+    fs::File,
+    io::{self, BufRead, BufReader, BufWriter, Write},
+    path::Path,
+};
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    camera_view, debug_headless_renderer, debug_renderer, DebugDraw, DebugDrawBlendMode,
+    DebugDrawMesh, DebugDrawSpace, DebugDrawSystem, DebugDrawVertex,
+};
+
+/// Records the meshes rendered by [`DebugDraw`] every frame while [`DebugDrawRecorder`] is
+/// recording. Recordings can be converted offline with the `debug_draw_replay` binary. Requires
+/// the `recording` feature.
+pub struct DebugDrawRecorderPlugin;
+
+impl Plugin for DebugDrawRecorderPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<DebugDraw>()
+            .init_resource::<DebugDrawRecorder>()
+            .add_system(
+                debug_recorder
+                    .in_set(DebugDrawSystem)
+                    .after(debug_renderer)
+                    .after(debug_headless_renderer),
+            );
+    }
+}
+
+/// Writes recorded frames to a JSON lines file, one [`DebugDrawRecordedFrame`] per line.
+/// Instanced draws are not recorded.
+#[derive(Resource, Default)]
+pub struct DebugDrawRecorder {
+    writer: Option<BufWriter<File>>,
+    frame: u64,
+}
+
+impl DebugDrawRecorder {
+    /// Starts a new recording, replacing the file at the given path.
+    pub fn start(&mut self, path: impl AsRef<Path>) -> io::Result<()> {
+        self.stop()?;
+        self.writer = Some(BufWriter::new(File::create(path)?));
+        self.frame = 0;
+        Ok(())
+    }
+
+    pub fn stop(&mut self) -> io::Result<()> {
+        match self.writer.take() {
+            Some(mut writer) => writer.flush(),
+            None => Ok(()),
+        }
+    }
+
+    pub fn is_recording(&self) -> bool {
+        self.writer.is_some()
+    }
+
+    pub fn record(&mut self, frame: &DebugDrawRecordedFrame) -> io::Result<()> {
+        if let Some(writer) = &mut self.writer {
+            serde_json::to_writer(&mut *writer, frame)?;
+            writer.write_all(b"\n")?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct DebugDrawRecordedFrame {
+    pub frame: u64,
+    /// Seconds since startup.
+    pub time: f32,
+    /// The area of the world seen by the camera, as `[min_x, min_y, max_x, max_y]`.
+    pub view: Option<[f32; 4]>,
+    pub meshes: Vec<DebugDrawRecordedMesh>,
+}
+
+impl DebugDrawRecordedFrame {
+    pub fn view(&self) -> Option<Rect> {
+        self.view
+            .map(|[min_x, min_y, max_x, max_y]| Rect::new(min_x, min_y, max_x, max_y))
+    }
+
+    /// Captures the meshes rendered by the last frame.
+    pub(crate) fn from_debug_draw(
+        debug_draw: &DebugDraw,
+        frame: u64,
+        time: f32,
+        view: Option<Rect>,
+    ) -> Self {
+        Self {
+            frame,
+            time,
+            view: view.map(|view| [view.min.x, view.min.y, view.max.x, view.max.y]),
+            meshes: debug_draw
+                .rendered_calls
+                .iter()
+                .map(|call| DebugDrawRecordedMesh {
+                    vertices: call
+                        .mesh
+                        .vertices
+                        .iter()
+                        .map(|vertex| {
+                            let [r, g, b, a] = vertex.color.as_rgba_f32();
+                            [vertex.position.x, vertex.position.y, r, g, b, a]
+                        })
+                        .collect(),
+                    indices: call.mesh.indices.clone(),
+                    depth: call.mesh.depth,
+                    screen: call.space != DebugDrawSpace::World,
+                    blend_mode: call.blend_mode,
+                    textured: call.mesh.texture.is_some(),
+                    category: call
+                        .category
+                        .as_ref()
+                        .map(|category| category.name().to_owned()),
+                })
+                .collect(),
+        }
+    }
+
+    /// The world space meshes of the frame, with their blend mode.
+    pub fn world_meshes(&self) -> Vec<(DebugDrawMesh, DebugDrawBlendMode)> {
+        self.meshes
+            .iter()
+            .filter(|mesh| !mesh.screen)
+            .map(|mesh| (mesh.to_mesh(), mesh.blend_mode))
+            .collect()
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct DebugDrawRecordedMesh {
+    /// Positions and colors of the vertices, as `[x, y, r, g, b, a]`.
+    pub vertices: Vec<[f32; 6]>,
+    pub indices: Vec<u32>,
+    pub depth: f32,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub screen: bool,
+    #[serde(default, skip_serializing_if = "is_alpha")]
+    pub blend_mode: DebugDrawBlendMode,
+    /// Whether the mesh was drawn with a texture. Textures aren't recorded, so replayed meshes
+    /// only have their vertex colors.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub textured: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub category: Option<String>,
+}
+
+impl DebugDrawRecordedMesh {
+    pub fn to_mesh(&self) -> DebugDrawMesh {
+        DebugDrawMesh {
+            vertices: self
+                .vertices
+                .iter()
+                .map(|&[x, y, r, g, b, a]| DebugDrawVertex {
+                    position: Vec2::new(x, y),
+                    color: Color::rgba(r, g, b, a),
+                    ..Default::default()
+                })
+                .collect(),
+            indices: self.indices.clone(),
+            depth: self.depth,
+            ..Default::default()
+        }
+    }
+}
+
+fn is_alpha(blend_mode: &DebugDrawBlendMode) -> bool {
+    *blend_mode == DebugDrawBlendMode::Alpha
+}
+
+/// Reads the frames of a recording one line at a time.
+pub fn read_debug_draw_recording(
+    path: impl AsRef<Path>,
+) -> io::Result<impl Iterator<Item = io::Result<DebugDrawRecordedFrame>>> {
+    let reader = BufReader::new(File::open(path)?);
+    Ok(reader
+        .lines()
+        .filter(|line| !matches!(line, Ok(line) if line.trim().is_empty()))
+        .map(|line| Ok(serde_json::from_str(&line?)?)))
+}
+
+fn debug_recorder(
+    debug_draw: Res<DebugDraw>,
+    mut recorder: ResMut<DebugDrawRecorder>,
+    time: Res<Time>,
+    camera_query: Query<(&Camera, &OrthographicProjection, &GlobalTransform)>,
+) {
+    if !recorder.is_recording() {
+        return;
+    }
+    let frame = DebugDrawRecordedFrame::from_debug_draw(
+        &debug_draw,
+        recorder.frame,
+        time.elapsed_seconds(),
+        camera_view(&camera_query),
+    );
+    recorder.frame += 1;
+    if let Err(error) = recorder.record(&frame) {
+        error!("Failed to record debug drawing, stopping: {}", error);
+        recorder.writer = None;
+    }
+}
@@ -1,6 +1,6 @@
 use bevy::prelude::*;
 
-use crate::{DebugDrawDrawable, DebugDrawMesh, DebugDrawVertex};
+use crate::{DebugDrawDrawable, DebugDrawInstance, DebugDrawMesh, DebugDrawVertex};
 
 #[derive(Clone, Copy, Debug)]
 pub struct DebugRectangle {
@@ -31,22 +31,39 @@ impl DebugDrawDrawable for DebugRectangle {
                 DebugDrawVertex {
                     position: self.position + rotation.rotate(self.size * Vec2::new(0.5, 0.5)),
                     color: self.color,
+                    ..Default::default()
                 },
                 DebugDrawVertex {
                     position: self.position + rotation.rotate(self.size * Vec2::new(-0.5, 0.5)),
                     color: self.color,
+                    ..Default::default()
                 },
                 DebugDrawVertex {
                     position: self.position + rotation.rotate(self.size * Vec2::new(0.5, -0.5)),
                     color: self.color,
+                    ..Default::default()
                 },
                 DebugDrawVertex {
                     position: self.position + rotation.rotate(self.size * Vec2::new(-0.5, -0.5)),
                     color: self.color,
+                    ..Default::default()
                 },
             ],
             indices: vec![0, 1, 2, 3, 2, 1],
             depth: self.depth,
+            ..Default::default()
         }
     }
+
+    fn to_instance(&self) -> Option<DebugDrawInstance> {
+        Some(DebugDrawInstance {
+            center: self.position,
+            size: self.size,
+            rotation: self.rotation,
+            segments: 0,
+            start_color: self.color,
+            end_color: self.color,
+            depth: self.depth,
+        })
+    }
 }
@@ -0,0 +1,465 @@
+#[cfg(unix)]
+use std::os::unix::{
+    fs::FileTypeExt,
+    net::{UnixListener, UnixStream},
+};
+#[cfg(unix)]
+use std::{fs, path::PathBuf};
+use std::{
+    io::{self, BufRead, BufReader, ErrorKind, Read, Write},
+    mem::{replace, take},
+    net::{TcpListener, TcpStream},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        mpsc::{self, Receiver, RecvTimeoutError, SyncSender, TrySendError},
+        Arc, Mutex,
+    },
+    thread,
+    time::Duration,
+};
+
+use bevy::prelude::*;
+
+use crate::{
+    camera_view, debug_headless_renderer, debug_renderer, DebugDraw, DebugDrawBlendMode,
+    DebugDrawCategory, DebugDrawRecordedFrame, DebugDrawSpace, DebugDrawSystem, DebugDrawTarget,
+};
+
+/// Frames waiting to be written to a viewer. Frames are dropped when a viewer falls behind.
+const DEBUG_DRAW_REMOTE_QUEUE: usize = 8;
+
+/// How long a viewer waits for data before checking whether it was disconnected.
+const DEBUG_DRAW_REMOTE_POLL: Duration = Duration::from_millis(250);
+
+/// Where remote frames are sent and received, as lines of JSON in the recording format.
+#[derive(Clone, Debug)]
+pub enum DebugDrawRemoteEndpoint {
+    /// A TCP address like `127.0.0.1:7878`. The source listens on it and viewers connect to it.
+    Tcp(String),
+    /// A Unix socket path. The source listens on it and viewers connect to it.
+    #[cfg(unix)]
+    Unix(PathBuf),
+    /// An in-process channel, to stand in for a socket in tests.
+    Loopback(DebugDrawLoopback),
+}
+
+/// An in-process connection between one source and one viewer.
+#[derive(Clone, Debug)]
+pub struct DebugDrawLoopback {
+    sender: SyncSender<Arc<str>>,
+    receiver: Arc<Mutex<Option<Receiver<Arc<str>>>>>,
+}
+
+impl DebugDrawLoopback {
+    pub fn new() -> Self {
+        let (sender, receiver) = mpsc::sync_channel(DEBUG_DRAW_REMOTE_QUEUE);
+        Self {
+            sender,
+            receiver: Arc::new(Mutex::new(Some(receiver))),
+        }
+    }
+}
+
+impl Default for DebugDrawLoopback {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Sends the meshes rendered every frame to remote viewers. It works with either
+/// [`crate::DebugDrawPlugin`] or [`crate::DebugDrawHeadlessPlugin`]. Requires the `remote` feature.
+pub struct DebugDrawRemoteSourcePlugin {
+    pub endpoint: DebugDrawRemoteEndpoint,
+}
+
+impl Plugin for DebugDrawRemoteSourcePlugin {
+    fn build(&self, app: &mut App) {
+        let outputs = DebugDrawRemoteOutputs::default();
+        if let Err(error) = outputs.listen(&self.endpoint) {
+            error!("Failed to listen for debug draw viewers: {}", error);
+        }
+        app.init_resource::<DebugDraw>()
+            .insert_resource(outputs)
+            .add_system(
+                debug_remote_source
+                    .in_set(DebugDrawSystem)
+                    .after(debug_renderer)
+                    .after(debug_headless_renderer),
+            );
+    }
+}
+
+#[derive(Resource, Default)]
+struct DebugDrawRemoteOutputs {
+    senders: Arc<Mutex<Vec<SyncSender<Arc<str>>>>>,
+    frame: u64,
+}
+
+impl DebugDrawRemoteOutputs {
+    fn listen(&self, endpoint: &DebugDrawRemoteEndpoint) -> io::Result<()> {
+        match endpoint {
+            DebugDrawRemoteEndpoint::Tcp(address) => {
+                let listener = TcpListener::bind(address)?;
+                let senders = self.senders.clone();
+                thread::spawn(move || {
+                    for stream in listener.incoming().flatten() {
+                        let _ = stream.set_nodelay(true);
+                        add_output(&senders, stream);
+                    }
+                });
+            }
+            #[cfg(unix)]
+            DebugDrawRemoteEndpoint::Unix(path) => {
+                // A socket left behind by a previous run would make binding fail. Any other file
+                // is left alone, and binding reports the conflict.
+                if fs::symlink_metadata(path).is_ok_and(|metadata| metadata.file_type().is_socket())
+                {
+                    fs::remove_file(path)?;
+                }
+                let listener = UnixListener::bind(path)?;
+                let senders = self.senders.clone();
+                thread::spawn(move || {
+                    for stream in listener.incoming().flatten() {
+                        add_output(&senders, stream);
+                    }
+                });
+            }
+            DebugDrawRemoteEndpoint::Loopback(loopback) => {
+                self.senders.lock().unwrap().push(loopback.sender.clone());
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Writes frames to a connected viewer from its own thread, so a slow viewer doesn't stall the app.
+fn add_output(senders: &Mutex<Vec<SyncSender<Arc<str>>>>, mut stream: impl Write + Send + 'static) {
+    let (sender, receiver) = mpsc::sync_channel::<Arc<str>>(DEBUG_DRAW_REMOTE_QUEUE);
+    senders.lock().unwrap().push(sender);
+    thread::spawn(move || {
+        for line in receiver {
+            if stream.write_all(line.as_bytes()).is_err() {
+                break;
+            }
+        }
+    });
+}
+
+fn debug_remote_source(
+    debug_draw: Res<DebugDraw>,
+    mut outputs: ResMut<DebugDrawRemoteOutputs>,
+    time: Res<Time>,
+    camera_query: Query<(&Camera, &OrthographicProjection, &GlobalTransform)>,
+) {
+    let outputs = outputs.as_mut();
+    let mut senders = outputs.senders.lock().unwrap();
+    if senders.is_empty() {
+        return;
+    }
+    let frame = DebugDrawRecordedFrame::from_debug_draw(
+        &debug_draw,
+        outputs.frame,
+        time.elapsed_seconds(),
+        camera_view(&camera_query),
+    );
+    outputs.frame += 1;
+    let line: Arc<str> = match serde_json::to_string(&frame) {
+        Ok(line) => (line + "\n").into(),
+        Err(error) => {
+            error!("Failed to serialize debug drawing: {}", error);
+            return;
+        }
+    };
+    senders.retain(|sender| {
+        !matches!(
+            sender.try_send(line.clone()),
+            Err(TrySendError::Disconnected(_))
+        )
+    });
+}
+
+/// Draws the frames received from remote sources. See [`DebugDrawRemote::connect`].
+pub struct DebugDrawRemoteViewerPlugin;
+
+impl Plugin for DebugDrawRemoteViewerPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<DebugDraw>()
+            .init_resource::<DebugDrawRemote>()
+            .add_system(debug_remote_viewer.before(DebugDrawSystem));
+    }
+}
+
+#[derive(Resource, Default)]
+pub struct DebugDrawRemote {
+    sources: Vec<DebugDrawRemoteSource>,
+}
+
+struct DebugDrawRemoteSource {
+    category: DebugDrawCategory,
+    /// Receives every frame, and `None` when the connection is lost.
+    receiver: Mutex<Receiver<Option<DebugDrawRecordedFrame>>>,
+    frame: Option<DebugDrawRecordedFrame>,
+    /// Tells the background thread to stop once the source is disconnected.
+    closed: Arc<AtomicBool>,
+}
+
+impl Drop for DebugDrawRemoteSource {
+    fn drop(&mut self) {
+        self.closed.store(true, Ordering::Relaxed);
+    }
+}
+
+impl DebugDrawRemote {
+    /// Receives frames from a source in the background, reconnecting when the connection is lost.
+    /// The world space meshes of its latest frame are drawn in a category named after the source,
+    /// so they can be toggled and tinted like any other category. Nothing is drawn while the
+    /// source is not connected.
+    pub fn connect(
+        &mut self,
+        name: impl Into<String>,
+        endpoint: DebugDrawRemoteEndpoint,
+    ) -> DebugDrawCategory {
+        let category = DebugDrawCategory::from(name.into());
+        let (sender, receiver) = mpsc::channel();
+        let closed = Arc::new(AtomicBool::new(false));
+        match endpoint {
+            DebugDrawRemoteEndpoint::Tcp(address) => {
+                spawn_reader(sender, closed.clone(), move || {
+                    let stream = TcpStream::connect(&address)?;
+                    stream.set_read_timeout(Some(DEBUG_DRAW_REMOTE_POLL))?;
+                    Ok(stream)
+                });
+            }
+            #[cfg(unix)]
+            DebugDrawRemoteEndpoint::Unix(path) => {
+                spawn_reader(sender, closed.clone(), move || {
+                    let stream = UnixStream::connect(&path)?;
+                    stream.set_read_timeout(Some(DEBUG_DRAW_REMOTE_POLL))?;
+                    Ok(stream)
+                });
+            }
+            DebugDrawRemoteEndpoint::Loopback(loopback) => {
+                let lines = loopback.receiver.lock().unwrap().take();
+                if let Some(lines) = lines {
+                    let closed = closed.clone();
+                    thread::spawn(move || {
+                        while !closed.load(Ordering::Relaxed) {
+                            match lines.recv_timeout(DEBUG_DRAW_REMOTE_POLL) {
+                                Ok(line) => {
+                                    if !send_line(&sender, line.as_bytes()) {
+                                        return;
+                                    }
+                                }
+                                Err(RecvTimeoutError::Timeout) => {}
+                                Err(RecvTimeoutError::Disconnected) => {
+                                    let _ = sender.send(None);
+                                    return;
+                                }
+                            }
+                        }
+                    });
+                } else {
+                    error!("A debug draw loopback can only be connected once");
+                }
+            }
+        }
+        self.sources.push(DebugDrawRemoteSource {
+            category: category.clone(),
+            receiver: Mutex::new(receiver),
+            frame: None,
+            closed,
+        });
+        category
+    }
+
+    /// Stops drawing a source and closes its connection.
+    pub fn disconnect(&mut self, category: &DebugDrawCategory) {
+        self.sources.retain(|source| source.category != *category);
+    }
+
+    /// The latest frame received from a source, or `None` while it is not connected.
+    pub fn frame(&self, category: &DebugDrawCategory) -> Option<&DebugDrawRecordedFrame> {
+        self.sources
+            .iter()
+            .find(|source| source.category == *category)
+            .and_then(|source| source.frame.as_ref())
+    }
+
+    pub fn sources(&self) -> impl Iterator<Item = &DebugDrawCategory> {
+        self.sources.iter().map(|source| &source.category)
+    }
+}
+
+fn spawn_reader<R: Read + Send + 'static>(
+    sender: mpsc::Sender<Option<DebugDrawRecordedFrame>>,
+    closed: Arc<AtomicBool>,
+    connect: impl Fn() -> io::Result<R> + Send + 'static,
+) {
+    thread::spawn(move || {
+        while !closed.load(Ordering::Relaxed) {
+            if let Ok(stream) = connect() {
+                let mut reader = BufReader::new(stream);
+                let mut line = Vec::new();
+                // Reads time out, so a silent source doesn't keep the thread alive forever.
+                while !closed.load(Ordering::Relaxed) {
+                    match reader.read_until(b'\n', &mut line) {
+                        Ok(0) => break,
+                        Ok(_) => {
+                            if !send_line(&sender, &line) {
+                                return;
+                            }
+                            line.clear();
+                        }
+                        Err(error)
+                            if matches!(
+                                error.kind(),
+                                ErrorKind::WouldBlock | ErrorKind::TimedOut
+                            ) => {}
+                        Err(_) => break,
+                    }
+                }
+                if sender.send(None).is_err() {
+                    return;
+                }
+            }
+            thread::sleep(Duration::from_secs(1));
+        }
+    });
+}
+
+/// Returns false once the viewer is gone.
+fn send_line(sender: &mpsc::Sender<Option<DebugDrawRecordedFrame>>, line: &[u8]) -> bool {
+    match serde_json::from_slice(line) {
+        Ok(frame) => sender.send(Some(frame)).is_ok(),
+        Err(error) => {
+            warn!("Ignored an invalid debug draw frame: {}", error);
+            true
+        }
+    }
+}
+
+fn debug_remote_viewer(mut remote: ResMut<DebugDrawRemote>, mut debug_draw: ResMut<DebugDraw>) {
+    // Received meshes are drawn as they were recorded, whatever state the app left behind.
+    let debug_draw = debug_draw.as_mut();
+    let target = replace(&mut debug_draw.target, DebugDrawTarget::Default);
+    let space = replace(&mut debug_draw.space, DebugDrawSpace::World);
+    let blend_mode = replace(&mut debug_draw.blend_mode, DebugDrawBlendMode::Alpha);
+    let transform_stack = take(&mut debug_draw.transform_stack);
+    for source in remote.sources.iter_mut() {
+        for frame in source.receiver.get_mut().unwrap().try_iter() {
+            source.frame = frame;
+        }
+        let Some(frame) = &source.frame else {
+            continue;
+        };
+        for mesh in frame.meshes.iter().filter(|mesh| !mesh.screen) {
+            debug_draw.blend_mode = mesh.blend_mode;
+            debug_draw.draw_in(source.category.clone(), mesh.to_mesh());
+        }
+    }
+    debug_draw.target = target;
+    debug_draw.space = space;
+    debug_draw.blend_mode = blend_mode;
+    debug_draw.transform_stack = transform_stack;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DebugDrawHeadlessPlugin, DebugRectangle};
+
+    fn draw_rectangle(mut debug_draw: ResMut<DebugDraw>) {
+        debug_draw.draw_blended(
+            DebugDrawBlendMode::Additive,
+            DebugRectangle {
+                size: Vec2::new(100., 50.),
+                color: Color::RED,
+                ..Default::default()
+            },
+        );
+    }
+
+    /// Updates the viewer until its drawing matches, or gives up after a few seconds.
+    fn update_until(viewer: &mut App, done: impl Fn(&DebugDraw) -> bool) -> bool {
+        for _ in 0..300 {
+            viewer.update();
+            if done(viewer.world.resource::<DebugDraw>()) {
+                return true;
+            }
+            thread::sleep(Duration::from_millis(10));
+        }
+        false
+    }
+
+    #[test]
+    fn loopback_round_trip() {
+        let loopback = DebugDrawLoopback::new();
+        let mut source = App::new();
+        source
+            .add_plugins(MinimalPlugins)
+            .add_plugin(DebugDrawHeadlessPlugin)
+            .add_plugin(DebugDrawRemoteSourcePlugin {
+                endpoint: DebugDrawRemoteEndpoint::Loopback(loopback.clone()),
+            })
+            .add_system(draw_rectangle);
+        let mut viewer = App::new();
+        viewer
+            .add_plugins(MinimalPlugins)
+            .add_plugin(DebugDrawHeadlessPlugin)
+            .add_plugin(DebugDrawRemoteViewerPlugin);
+        let category = viewer
+            .world
+            .resource_mut::<DebugDrawRemote>()
+            .connect("source", DebugDrawRemoteEndpoint::Loopback(loopback));
+        // Viewer state must not leak into the received drawing.
+        viewer
+            .world
+            .resource_mut::<DebugDraw>()
+            .push_transform(bevy::math::Affine2::from_translation(Vec2::new(1000., 0.)));
+
+        source.update();
+        source.update();
+        let sent = source
+            .world
+            .resource::<DebugDraw>()
+            .rendered_meshes()
+            .map(|(mesh, _)| mesh.clone())
+            .collect::<Vec<_>>();
+        assert_eq!(sent.len(), 1);
+
+        assert!(update_until(&mut viewer, |debug_draw| debug_draw
+            .rendered_meshes()
+            .next()
+            .is_some()));
+        let remote = viewer.world.resource::<DebugDrawRemote>();
+        assert_eq!(remote.frame(&category).unwrap().meshes.len(), 1);
+        let received = viewer
+            .world
+            .resource::<DebugDraw>()
+            .rendered_meshes()
+            .map(|(mesh, space)| (mesh.clone(), space))
+            .collect::<Vec<_>>();
+        assert_eq!(received.len(), 1);
+        assert_eq!(received[0].1, DebugDrawSpace::World);
+        assert_eq!(
+            viewer.world.resource::<DebugDraw>().rendered_calls[0].blend_mode,
+            DebugDrawBlendMode::Additive
+        );
+        let positions = |mesh: &crate::DebugDrawMesh| {
+            mesh.vertices
+                .iter()
+                .map(|vertex| vertex.position)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(positions(&received[0].0), positions(&sent[0]));
+
+        // The drawing goes away with the source.
+        drop(source);
+        assert!(update_until(&mut viewer, |debug_draw| debug_draw
+            .rendered_meshes()
+            .next()
+            .is_none()));
+        let remote = viewer.world.resource::<DebugDrawRemote>();
+        assert!(remote.frame(&category).is_none());
+    }
+}
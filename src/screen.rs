@@ -0,0 +1,106 @@
+use bevy::{
+    core_pipeline::clear_color::ClearColorConfig, prelude::*, render::view::RenderLayers,
+    ui::camera_config::UiCameraConfig,
+};
+
+use crate::DebugDraw;
+
+/// The default render layer reserved for screen-space draws. Only the overlay camera spawned by
+/// the plugin should render this layer: cameras with [`RenderLayers::all`] render the overlay
+/// too. Use [`DebugDraw::set_screen_layer`] to move it to a layer the game doesn't use.
+pub const DEBUG_DRAW_SCREEN_LAYER: u8 = RenderLayers::TOTAL_LAYERS as u8 - 1;
+
+/// The camera order of the overlay camera, high enough to render after any game camera.
+const DEBUG_DRAW_SCREEN_CAMERA_ORDER: isize = 1000;
+
+/// Where the vertices of a draw live.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DebugDrawSpace {
+    /// World coordinates, seen through the targeted cameras.
+    #[default]
+    World,
+    /// Logical pixels relative to an anchor on the primary window, with y pointing up. Drawn on
+    /// top of everything by an overlay camera, ignoring the current target.
+    Screen(DebugDrawAnchor),
+}
+
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DebugDrawAnchor {
+    TopLeft,
+    Top,
+    TopRight,
+    Left,
+    #[default]
+    Center,
+    Right,
+    BottomLeft,
+    Bottom,
+    BottomRight,
+}
+
+impl DebugDrawAnchor {
+    /// The anchor position in a window of the given logical size, relative to its center.
+    pub fn position(&self, window_size: Vec2) -> Vec2 {
+        let half_size = window_size * 0.5;
+        match self {
+            DebugDrawAnchor::TopLeft => Vec2::new(-half_size.x, half_size.y),
+            DebugDrawAnchor::Top => Vec2::new(0., half_size.y),
+            DebugDrawAnchor::TopRight => Vec2::new(half_size.x, half_size.y),
+            DebugDrawAnchor::Left => Vec2::new(-half_size.x, 0.),
+            DebugDrawAnchor::Center => Vec2::ZERO,
+            DebugDrawAnchor::Right => Vec2::new(half_size.x, 0.),
+            DebugDrawAnchor::BottomLeft => Vec2::new(-half_size.x, -half_size.y),
+            DebugDrawAnchor::Bottom => Vec2::new(0., -half_size.y),
+            DebugDrawAnchor::BottomRight => Vec2::new(half_size.x, -half_size.y),
+        }
+    }
+}
+
+impl DebugDraw {
+    /// Sets the render layer of screen-space draws and the overlay camera. World draws never
+    /// render on it, even when they target [`RenderLayers::all`].
+    ///
+    /// Panics if the layer is not below [`RenderLayers::TOTAL_LAYERS`].
+    pub fn set_screen_layer(&mut self, layer: u8) {
+        assert!((layer as usize) < RenderLayers::TOTAL_LAYERS);
+        self.screen_layer = Some(layer);
+    }
+
+    pub fn screen_layer(&self) -> u8 {
+        self.screen_layer.unwrap_or(DEBUG_DRAW_SCREEN_LAYER)
+    }
+}
+
+#[derive(Component)]
+pub(crate) struct DebugDrawScreenCamera;
+
+pub(crate) fn debug_screen_camera(
+    mut commands: Commands,
+    debug_draw: Res<DebugDraw>,
+    mut screen_camera_query: Query<&mut RenderLayers, With<DebugDrawScreenCamera>>,
+) {
+    let render_layers = RenderLayers::layer(debug_draw.screen_layer());
+    for mut screen_camera_layers in screen_camera_query.iter_mut() {
+        if *screen_camera_layers != render_layers {
+            *screen_camera_layers = render_layers;
+        }
+    }
+    if screen_camera_query.is_empty() && debug_draw.has_screen_draws() {
+        commands
+            .spawn(Camera2dBundle {
+                camera: Camera {
+                    order: DEBUG_DRAW_SCREEN_CAMERA_ORDER,
+                    ..Default::default()
+                },
+                camera_2d: Camera2d {
+                    clear_color: ClearColorConfig::None,
+                },
+                ..Default::default()
+            })
+            .insert((
+                DebugDrawScreenCamera,
+                render_layers,
+                UiCameraConfig { show_ui: false },
+            ));
+    }
+}
@@ -0,0 +1,133 @@
+use std::sync::{
+    mpsc::{channel, Receiver, Sender},
+    Mutex, OnceLock,
+};
+
+use crate::{
+    DebugDraw, DebugDrawAnchor, DebugDrawBlendMode, DebugDrawCall, DebugDrawCategory,
+    DebugDrawDrawable, DebugDrawDuration, DebugDrawSpace, DebugDrawTarget, DebugDrawTimedCall,
+};
+
+/// A cloneable handle to send draws to [`DebugDraw`] from any thread, such as async tasks. Draws
+/// are tessellated on the sending thread and rendered in the next frame.
+#[derive(Clone)]
+pub struct DebugDrawSender {
+    sender: Sender<DebugDrawMessage>,
+    target: DebugDrawTarget,
+    space: DebugDrawSpace,
+    blend_mode: DebugDrawBlendMode,
+    category: Option<DebugDrawCategory>,
+}
+
+struct DebugDrawMessage {
+    call: DebugDrawCall,
+    duration: Option<(DebugDrawDuration, bool)>,
+}
+
+pub(crate) struct DebugDrawChannel {
+    sender: Sender<DebugDrawMessage>,
+    receiver: Mutex<Receiver<DebugDrawMessage>>,
+}
+
+impl DebugDrawSender {
+    pub fn draw<T: DebugDrawDrawable>(&self, mesh: T) {
+        self.send(mesh, None);
+    }
+
+    /// Keeps drawing a drawable for the given duration, optionally fading it out.
+    pub fn draw_for<T: DebugDrawDrawable>(&self, duration: DebugDrawDuration, fade: bool, mesh: T) {
+        self.send(mesh, Some((duration, fade)));
+    }
+
+    pub fn with_target(mut self, target: impl Into<DebugDrawTarget>) -> Self {
+        self.target = target.into();
+        self
+    }
+
+    /// Draws in screen space, in logical pixels relative to the anchor.
+    pub fn with_screen_anchor(mut self, anchor: DebugDrawAnchor) -> Self {
+        self.space = DebugDrawSpace::Screen(anchor);
+        self
+    }
+
+    pub fn with_blend_mode(mut self, blend_mode: DebugDrawBlendMode) -> Self {
+        self.blend_mode = blend_mode;
+        self
+    }
+
+    pub fn with_category(mut self, category: impl Into<DebugDrawCategory>) -> Self {
+        self.category = Some(category.into());
+        self
+    }
+
+    fn send<T: DebugDrawDrawable>(&self, mesh: T, duration: Option<(DebugDrawDuration, bool)>) {
+        // The receiving end only goes away with the `DebugDraw` resource, and the draw can be
+        // dropped then.
+        let _ = self.sender.send(DebugDrawMessage {
+            call: DebugDrawCall {
+                mesh: mesh.to_mesh(),
+                target: self.target,
+                space: self.space,
+                category: self.category.clone(),
+                blend_mode: self.blend_mode,
+            },
+            duration,
+        });
+    }
+}
+
+static GLOBAL_SENDER: OnceLock<DebugDrawSender> = OnceLock::new();
+
+/// The sender installed with [`DebugDraw::install_global_sender`], for code without access to the
+/// ECS.
+pub fn global_debug_draw() -> Option<&'static DebugDrawSender> {
+    GLOBAL_SENDER.get()
+}
+
+impl DebugDraw {
+    /// Returns a new sender, drawing with the default settings.
+    pub fn sender(&self) -> DebugDrawSender {
+        let channel = self.channel.get_or_init(|| {
+            let (sender, receiver) = channel();
+            DebugDrawChannel {
+                sender,
+                receiver: Mutex::new(receiver),
+            }
+        });
+        DebugDrawSender {
+            sender: channel.sender.clone(),
+            target: DebugDrawTarget::default(),
+            space: DebugDrawSpace::default(),
+            blend_mode: DebugDrawBlendMode::default(),
+            category: None,
+        }
+    }
+
+    /// Makes a sender reachable from anywhere with [`global_debug_draw`]. Only the first call
+    /// has an effect.
+    pub fn install_global_sender(&self) {
+        let sender = self.sender();
+        let _ = GLOBAL_SENDER.set(sender);
+    }
+
+    /// Adds the draws sent since the last frame.
+    pub(crate) fn receive_sent(&mut self) {
+        let Some(channel) = self.channel.take() else {
+            return;
+        };
+        for message in channel.receiver.lock().unwrap().try_iter() {
+            let call = message.call;
+            if !self.is_category_enabled(call.category.as_ref()) {
+                continue;
+            }
+            match message.duration {
+                Some((duration, fade)) => {
+                    self.timed
+                        .push(DebugDrawTimedCall::new(call, duration, fade));
+                }
+                None => self.calls.push(call),
+            }
+        }
+        self.channel = OnceLock::from(channel);
+    }
+}
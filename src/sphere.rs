@@ -0,0 +1,78 @@
+use std::f32::consts::{PI, TAU};
+
+use bevy::prelude::*;
+
+use crate::{DebugDrawDrawable3d, DebugDrawMesh3d, DebugDrawVertex3d};
+
+#[derive(Clone, Copy, Debug)]
+pub struct DebugSphere {
+    pub position: Vec3,
+    pub radius: f32,
+    pub segments: u8,
+    pub color: Color,
+    /// Draws three great circles, as lines of the given `thickness`.
+    pub wireframe: bool,
+    pub thickness: f32,
+}
+
+impl Default for DebugSphere {
+    fn default() -> Self {
+        Self {
+            position: Vec3::ZERO,
+            radius: 0.5,
+            segments: 32,
+            color: Color::BLACK,
+            wireframe: false,
+            thickness: 0.02,
+        }
+    }
+}
+
+impl DebugDrawDrawable3d for DebugSphere {
+    fn to_mesh_3d(&self) -> DebugDrawMesh3d {
+        let mut mesh = DebugDrawMesh3d::new();
+        let segments = self.segments.max(3);
+        if self.wireframe {
+            for (u, v) in [(Vec3::X, Vec3::Y), (Vec3::X, Vec3::Z), (Vec3::Y, Vec3::Z)] {
+                mesh.add_ring(
+                    self.position,
+                    u,
+                    v,
+                    self.radius,
+                    segments,
+                    self.thickness,
+                    self.color,
+                );
+            }
+        } else {
+            let segments = segments as u32;
+            let rings = (segments / 2).max(2);
+            for ring in 0..=rings {
+                let polar = ring as f32 / rings as f32 * PI;
+                for segment in 0..segments {
+                    let azimuth = segment as f32 / segments as f32 * TAU;
+                    mesh.vertices.push(DebugDrawVertex3d {
+                        position: self.position
+                            + Vec3::new(
+                                polar.sin() * azimuth.cos(),
+                                polar.cos(),
+                                polar.sin() * azimuth.sin(),
+                            ) * self.radius,
+                        color: self.color,
+                    });
+                }
+            }
+            for ring in 0..rings {
+                for segment in 0..segments {
+                    let next_segment = (segment + 1) % segments;
+                    let a = ring * segments + segment;
+                    let b = ring * segments + next_segment;
+                    let c = (ring + 1) * segments + segment;
+                    let d = (ring + 1) * segments + next_segment;
+                    mesh.indices.extend_from_slice(&[a, c, b, b, c, d]);
+                }
+            }
+        }
+        mesh
+    }
+}
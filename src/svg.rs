@@ -0,0 +1,210 @@
+use std::{cmp::Ordering, fmt::Write, fs, path::PathBuf};
+
+use bevy::prelude::*;
+
+use crate::{DebugDraw, DebugDrawMesh, DebugDrawSpace};
+
+/// Adds a hotkey that saves the last rendered frame of [`DebugDraw`] as an SVG file, configured
+/// by [`DebugDrawSvgHotkey`].
+pub struct DebugDrawSvgPlugin;
+
+impl Plugin for DebugDrawSvgPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<DebugDraw>()
+            .init_resource::<DebugDrawSvgHotkey>()
+            .add_system(debug_svg_hotkey);
+    }
+}
+
+#[derive(Resource, Clone, Debug)]
+pub struct DebugDrawSvgHotkey {
+    pub key: KeyCode,
+    /// The file written when the key is pressed. It is overwritten on every press.
+    pub path: PathBuf,
+}
+
+impl Default for DebugDrawSvgHotkey {
+    fn default() -> Self {
+        Self {
+            key: KeyCode::F10,
+            path: PathBuf::from("debug_draw.svg"),
+        }
+    }
+}
+
+/// Serializes debug meshes into an SVG document, in depth order. Each single colored mesh becomes
+/// one path, so translucent draws overlap like on screen. Gradients are approximated with one path
+/// per triangle, filled with its average color, and textures are ignored.
+#[derive(Clone, Debug)]
+pub struct DebugDrawSvg {
+    /// The area of the world shown by the document.
+    pub view: Rect,
+    pub background: Option<Color>,
+}
+
+impl Default for DebugDrawSvg {
+    fn default() -> Self {
+        Self {
+            view: Rect::new(-640., -360., 640., 360.),
+            background: None,
+        }
+    }
+}
+
+impl DebugDrawSvg {
+    pub fn export<'a>(&self, meshes: impl IntoIterator<Item = &'a DebugDrawMesh>) -> String {
+        let mut meshes = meshes.into_iter().collect::<Vec<_>>();
+        meshes.sort_by(|a, b| a.depth.partial_cmp(&b.depth).unwrap_or(Ordering::Equal));
+
+        let size = self.view.size();
+        let mut svg = String::new();
+        let _ = writeln!(
+            svg,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{}" height="{}" viewBox="0 0 {} {}">"#,
+            size.x, size.y, size.x, size.y
+        );
+        if let Some(background) = self.background {
+            let _ = writeln!(
+                svg,
+                r#"<rect width="100%" height="100%" {}/>"#,
+                fill(to_u8(Vec4::from(background.as_rgba_f32())))
+            );
+        }
+        for mesh in meshes {
+            let mut triangles = Vec::new();
+            for triangle in mesh.indices.chunks_exact(3) {
+                let vertices = [
+                    &mesh.vertices[triangle[0] as usize],
+                    &mesh.vertices[triangle[1] as usize],
+                    &mesh.vertices[triangle[2] as usize],
+                ];
+                let mut positions = vertices.map(|vertex| self.to_document(vertex.position));
+                let area = (positions[1] - positions[0]).perp_dot(positions[2] - positions[0]);
+                if area == 0. || !area.is_finite() {
+                    continue;
+                }
+                if area < 0. {
+                    positions.swap(1, 2);
+                }
+                let color = vertices
+                    .iter()
+                    .map(|vertex| Vec4::from(vertex.color.as_rgba_f32()))
+                    .sum::<Vec4>()
+                    / 3.;
+                let color = to_u8(color);
+                if color[3] == 0 {
+                    continue;
+                }
+                triangles.push((color, positions));
+            }
+            let Some((first, _)) = triangles.first() else {
+                continue;
+            };
+            if triangles.iter().all(|(color, _)| color == first) {
+                // Triangles are written with the same winding, so the nonzero fill rule merges
+                // them into a single shape without seams.
+                let mut path = String::new();
+                for (_, positions) in &triangles {
+                    write_triangle(&mut path, positions);
+                }
+                let _ = writeln!(svg, r#"<path d="{}" {}/>"#, path, fill(*first));
+            } else {
+                for (color, positions) in &triangles {
+                    let mut path = String::new();
+                    write_triangle(&mut path, positions);
+                    let _ = writeln!(svg, r#"<path d="{}" {}/>"#, path, fill(*color));
+                }
+            }
+        }
+        svg.push_str("</svg>\n");
+        svg
+    }
+
+    /// Exports the world space meshes rendered by the last frame. Instanced draws are not
+    /// included.
+    pub fn export_debug_draw(&self, debug_draw: &DebugDraw) -> String {
+        self.export(
+            debug_draw
+                .rendered_meshes()
+                .filter(|(_, space)| *space == DebugDrawSpace::World)
+                .map(|(mesh, _)| mesh),
+        )
+    }
+
+    /// Maps a world position to document coordinates, with y down.
+    fn to_document(&self, position: Vec2) -> Vec2 {
+        Vec2::new(position.x - self.view.min.x, self.view.max.y - position.y)
+    }
+}
+
+fn write_triangle(path: &mut String, positions: &[Vec2; 3]) {
+    let _ = write!(
+        path,
+        "M{} {}L{} {}L{} {}Z",
+        positions[0].x,
+        positions[0].y,
+        positions[1].x,
+        positions[1].y,
+        positions[2].x,
+        positions[2].y
+    );
+}
+
+fn to_u8(color: Vec4) -> [u8; 4] {
+    color
+        .to_array()
+        .map(|channel| (channel.clamp(0., 1.) * 255.).round() as u8)
+}
+
+fn fill(color: [u8; 4]) -> String {
+    let [r, g, b, a] = color;
+    if a == 255 {
+        format!(r##"fill="#{:02x}{:02x}{:02x}""##, r, g, b)
+    } else {
+        format!(
+            r##"fill="#{:02x}{:02x}{:02x}" fill-opacity="{}""##,
+            r,
+            g,
+            b,
+            a as f32 / 255.
+        )
+    }
+}
+
+fn debug_svg_hotkey(
+    debug_draw: Res<DebugDraw>,
+    hotkey: Res<DebugDrawSvgHotkey>,
+    keys: Option<Res<Input<KeyCode>>>,
+    camera_query: Query<(&Camera, &OrthographicProjection, &GlobalTransform)>,
+) {
+    if !keys.is_some_and(|keys| keys.just_pressed(hotkey.key)) {
+        return;
+    }
+    let view = camera_view(&camera_query).unwrap_or(DebugDrawSvg::default().view);
+    let svg = DebugDrawSvg {
+        view,
+        ..Default::default()
+    }
+    .export_debug_draw(&debug_draw);
+    match fs::write(&hotkey.path, svg) {
+        Ok(()) => info!("Saved debug drawing to {}", hotkey.path.display()),
+        Err(error) => error!("Failed to save debug drawing: {}", error),
+    }
+}
+
+/// The area of the world seen by the first active orthographic camera.
+pub(crate) fn camera_view(
+    camera_query: &Query<(&Camera, &OrthographicProjection, &GlobalTransform)>,
+) -> Option<Rect> {
+    camera_query
+        .iter()
+        .filter(|(camera, ..)| camera.is_active)
+        .min_by_key(|(camera, ..)| camera.order)
+        .map(|(_, projection, transform)| {
+            let (scale, _, translation) = transform.to_scale_rotation_translation();
+            Rect::from_center_size(
+                translation.truncate() + projection.area.center() * scale.truncate(),
+                projection.area.size() * scale.truncate(),
+            )
+        })
+}
@@ -0,0 +1,50 @@
+use bevy::{ecs::query::ReadOnlyWorldQuery, prelude::*, render::view::RenderLayers};
+
+/// Selects which cameras see a draw.
+///
+/// Every target becomes its own mesh tagged with the matching [`RenderLayers`], so only cameras
+/// sharing one of those layers render it.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DebugDrawTarget {
+    /// The default render layer, seen by every camera without a [`RenderLayers`] component.
+    #[default]
+    Default,
+    /// The given layers, except the screen layer of [`DebugDraw`](crate::DebugDraw).
+    Layers(RenderLayers),
+    /// Uses the render layers of the given camera. Give the camera layers of its own to keep other
+    /// cameras from rendering these draws too.
+    Camera(Entity),
+}
+
+impl From<RenderLayers> for DebugDrawTarget {
+    fn from(value: RenderLayers) -> Self {
+        DebugDrawTarget::Layers(value)
+    }
+}
+
+impl From<Entity> for DebugDrawTarget {
+    fn from(value: Entity) -> Self {
+        DebugDrawTarget::Camera(value)
+    }
+}
+
+impl DebugDrawTarget {
+    pub(crate) fn render_layers<F: ReadOnlyWorldQuery>(
+        &self,
+        camera_query: &Query<Option<&RenderLayers>, F>,
+        screen_layer: u8,
+    ) -> RenderLayers {
+        let render_layers = match self {
+            DebugDrawTarget::Default => RenderLayers::default(),
+            DebugDrawTarget::Layers(render_layers) => *render_layers,
+            DebugDrawTarget::Camera(camera_entity) => camera_query
+                .get(*camera_entity)
+                .ok()
+                .flatten()
+                .copied()
+                .unwrap_or_default(),
+        };
+        // Keeps world draws out of the overlay camera.
+        render_layers.without(screen_layer)
+    }
+}
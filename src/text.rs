@@ -1,24 +1,23 @@
-use std::{collections::HashMap, mem::take, sync::RwLock};
+use std::mem::take;
 
 use bevy::prelude::*;
-use lazy_static::lazy_static;
 use ttf2mesh_triangulation::Triangulator;
-use ttf_parser::{Face, GlyphId};
+use ttf_parser::{
+    gpos::{PairAdjustment, PositioningSubtable},
+    Face, GlyphId, Tag,
+};
 
-use crate::{DebugDrawDrawable, DebugDrawMesh, DebugDrawVertex};
+use crate::{DebugDrawDrawable, DebugDrawMesh, DebugDrawVertex, DebugTextFont, DebugTextFontData};
 
-lazy_static! {
-    static ref TTF_FACE: Face<'static> =
-        Face::parse(include_bytes!("./FiraSans-Bold.ttf"), 0).unwrap();
-    static ref GLYPH_CACHE: RwLock<HashMap<GlyphId, Glyph>> = RwLock::new(HashMap::new());
-}
 const BASE_SCALE: f32 = 0.02;
-const LINE_HEIGHT: f32 = 1200.;
-const RESOLUTION: usize = 3;
-const INVERSE_RESOLUTION: f32 = 1. / (RESOLUTION as f32);
+/// Font units are scaled to this many units per em, the size of the bundled font's.
+const EM_SIZE: f32 = 1000.;
+/// Bounds the segments a single curve is flattened into.
+const MAX_CURVE_SEGMENTS: u32 = 64;
 
 #[derive(Clone)]
-struct Glyph {
+pub(crate) struct Glyph {
+    id: GlyphId,
     triangles: Vec<[[f32; 2]; 3]>,
     horizontal_advance: u16,
 }
@@ -32,6 +31,28 @@ pub struct DebugText {
     pub alignment: DebugTextAlignment,
     pub vertical_alignment: DebugTextVerticalAlignment,
     pub depth: f32,
+    pub font: DebugTextFont,
+    /// The maximum distance between the curves of glyphs and their flattened outlines, in world
+    /// units. Glyphs are cached at power of two tolerances, rounded down.
+    pub tolerance: f32,
+    /// Adjusts the space between pairs of glyphs, from the font's GPOS or `kern` table.
+    pub kerning: bool,
+    /// The width lines are wrapped or truncated at, in world units.
+    pub max_width: Option<f32>,
+    pub max_lines: Option<usize>,
+    pub wrap: DebugTextWrap,
+    /// What happens to text beyond `max_lines`, or beyond `max_width` without wrapping.
+    pub overflow: DebugTextOverflow,
+    /// Multiplies the distance between lines.
+    pub line_spacing: f32,
+    /// Extra space after every character, in ems.
+    pub letter_spacing: f32,
+    /// The distance between tab stops, in spaces.
+    pub tab_width: f32,
+    /// Draws a box behind the text.
+    pub background: Option<Color>,
+    /// The space between the text and the edges of its background, in world units.
+    pub padding: f32,
 }
 
 impl Default for DebugText {
@@ -44,6 +65,18 @@ impl Default for DebugText {
             alignment: DebugTextAlignment::Left,
             vertical_alignment: DebugTextVerticalAlignment::Top,
             depth: 0.,
+            font: DebugTextFont::DEFAULT,
+            tolerance: 0.1,
+            kerning: true,
+            max_width: None,
+            max_lines: None,
+            wrap: DebugTextWrap::Word,
+            overflow: DebugTextOverflow::Clip,
+            line_spacing: 1.,
+            letter_spacing: 0.,
+            tab_width: 4.,
+            background: None,
+            padding: 0.,
         }
     }
 }
@@ -62,120 +95,457 @@ pub enum DebugTextVerticalAlignment {
     Bottom,
 }
 
-enum Character {
-    Glyph(Glyph),
-    Newline,
+#[derive(Clone, Debug, Copy, PartialEq, Eq)]
+pub enum DebugTextWrap {
+    /// Lines longer than `max_width` are overflowing.
+    None,
+    /// Breaks lines between words, and inside words wider than a line.
+    Word,
+    /// Breaks lines between any two characters.
+    Character,
 }
 
-impl DebugDrawDrawable for DebugText {
-    fn to_mesh(&self) -> DebugDrawMesh {
-        let mut characters = vec![];
-        for char in self.text.chars() {
-            if char == '\n' {
-                characters.push(Character::Newline);
-            } else if let Some(glyph_id) = TTF_FACE.glyph_index(char) {
-                characters.push(Character::Glyph(
-                    if let Some(glyph) = {
-                        let glyph_cache = GLYPH_CACHE.read().expect("failed to lock mesh cache");
-                        glyph_cache.get(&glyph_id).cloned()
-                    } {
-                        glyph
-                    } else {
-                        let mut triangulator_builder = TriangulatorBuilder::default();
-                        TTF_FACE.outline_glyph(glyph_id, &mut triangulator_builder);
-                        let triangles = if triangulator_builder.has_contours {
-                            if let Ok(triangles) = triangulator_builder.triangulator.triangulate() {
-                                triangles
-                            } else {
-                                vec![]
-                            }
-                        } else {
-                            vec![]
-                        };
-                        let glyph = Glyph {
-                            triangles,
-                            horizontal_advance: TTF_FACE.glyph_hor_advance(glyph_id).unwrap_or(0),
-                        };
-                        {
-                            let mut glyph_cache =
-                                GLYPH_CACHE.write().expect("failed to lock mesh cache");
-                            glyph_cache.insert(glyph_id, glyph.clone());
+#[derive(Clone, Debug, Copy, PartialEq, Eq)]
+pub enum DebugTextOverflow {
+    /// Drops the overflowing text.
+    Clip,
+    /// Drops the overflowing text and ends the last line with an ellipsis.
+    Ellipsis,
+}
+
+#[derive(Clone)]
+struct PlacedGlyph {
+    char: char,
+    glyph: Glyph,
+    x: f32,
+    advance: f32,
+}
+
+impl PlacedGlyph {
+    fn is_whitespace(&self) -> bool {
+        self.char.is_whitespace()
+    }
+}
+
+/// Spacing shared by every line of a text, in world units.
+struct LineMetrics<'a> {
+    font: &'a DebugTextFontData,
+    face: Face<'a>,
+    kerning: bool,
+    scale: f32,
+    letter_spacing: f32,
+    tab: f32,
+}
+
+#[derive(Default)]
+struct Line {
+    glyphs: Vec<PlacedGlyph>,
+}
+
+impl Line {
+    fn end(&self) -> f32 {
+        self.glyphs
+            .last()
+            .map(|glyph| glyph.x + glyph.advance)
+            .unwrap_or(0.)
+    }
+
+    /// The width without trailing whitespace, which may hang past the edge, nor the letter spacing
+    /// after the last glyph.
+    fn width(&self, metrics: &LineMetrics<'_>) -> f32 {
+        self.glyphs
+            .iter()
+            .rev()
+            .find(|glyph| !glyph.is_whitespace())
+            .map(|glyph| glyph.x + glyph.advance - metrics.letter_spacing)
+            .unwrap_or(0.)
+    }
+
+    /// The width including trailing whitespace, without the letter spacing after the last glyph.
+    fn full_width(&self, metrics: &LineMetrics<'_>) -> f32 {
+        match self.glyphs.last() {
+            Some(glyph) if glyph.char == '\t' => glyph.x + glyph.advance,
+            Some(glyph) => glyph.x + glyph.advance - metrics.letter_spacing,
+            None => 0.,
+        }
+    }
+
+    /// Adds a glyph at the end of the line, kerned against the previous one. Tabs move to the
+    /// next tab stop.
+    fn place(&mut self, char: char, glyph: Glyph, metrics: &LineMetrics<'_>) {
+        let mut x = self.end();
+        if metrics.kerning && char != '\t' {
+            if let Some(previous) = self.glyphs.last().filter(|previous| previous.char != '\t') {
+                x += kerning(metrics.font, &metrics.face, previous.glyph.id, glyph.id) as f32
+                    * metrics.scale;
+            }
+        }
+        let advance = if char == '\t' {
+            if metrics.tab > 0. {
+                ((x / metrics.tab).floor() + 1.) * metrics.tab - x
+            } else {
+                0.
+            }
+        } else {
+            glyph.horizontal_advance as f32 * metrics.scale + metrics.letter_spacing
+        };
+        self.glyphs.push(PlacedGlyph {
+            char,
+            glyph,
+            x,
+            advance,
+        });
+    }
+
+    fn trim_end(&mut self) {
+        while self.glyphs.last().is_some_and(PlacedGlyph::is_whitespace) {
+            self.glyphs.pop();
+        }
+    }
+}
+
+/// Breaks a text into lines, built on the horizontal advance of its glyphs.
+struct Layout<'a> {
+    text: &'a DebugText,
+    detail: i8,
+    metrics: LineMetrics<'a>,
+    lines: Vec<Line>,
+}
+
+impl<'a> Layout<'a> {
+    fn new(text: &'a DebugText) -> Self {
+        let (font, face) = text.font.resolve();
+        let scale = text.scale * BASE_SCALE * EM_SIZE / face.units_per_em() as f32;
+        // The tolerance in font units, as a power of two.
+        let detail = (text.tolerance / scale).log2().floor().clamp(-8., 12.) as i8;
+        let space = load_glyph(font, &face, detail, ' ')
+            .map(|glyph| glyph.horizontal_advance)
+            .unwrap_or(0);
+        let mut layout = Self {
+            text,
+            detail,
+            metrics: LineMetrics {
+                font,
+                kerning: text.kerning,
+                scale,
+                letter_spacing: text.letter_spacing * face.units_per_em() as f32 * scale,
+                tab: space as f32 * scale * text.tab_width,
+                face,
+            },
+            lines: vec![Line::default()],
+        };
+        for char in text.text.chars() {
+            layout.push(char);
+        }
+        layout.truncate();
+        layout
+    }
+
+    fn load_glyph(&self, char: char) -> Option<Glyph> {
+        load_glyph(self.metrics.font, &self.metrics.face, self.detail, char)
+    }
+
+    fn max_width(&self) -> f32 {
+        self.text.max_width.unwrap_or(f32::INFINITY)
+    }
+
+    /// The width a line is aligned by. Trailing whitespace only hangs past the edge of text with a
+    /// maximum width.
+    fn aligned_width(&self, line: &Line) -> f32 {
+        if self.text.max_width.is_some() {
+            line.width(&self.metrics)
+        } else {
+            line.full_width(&self.metrics)
+        }
+    }
+
+    fn push(&mut self, char: char) {
+        if char == '\n' {
+            self.lines.push(Line::default());
+            return;
+        }
+        let glyph = if char == '\t' {
+            Some(Glyph {
+                id: GlyphId(0),
+                triangles: vec![],
+                horizontal_advance: 0,
+            })
+        } else {
+            self.load_glyph(char)
+        };
+        let Some(glyph) = glyph else {
+            return;
+        };
+        self.lines
+            .last_mut()
+            .unwrap()
+            .place(char, glyph, &self.metrics);
+        if self.text.wrap != DebugTextWrap::None && !char.is_whitespace() {
+            self.wrap();
+        }
+    }
+
+    /// Moves the end of the last line to a new line until it fits. Words are moved whole when
+    /// something precedes them, and broken at the character otherwise.
+    fn wrap(&mut self) {
+        let max_width = self.max_width();
+        loop {
+            let line = self.lines.last_mut().unwrap();
+            if line.width(&self.metrics) <= max_width || line.glyphs.len() < 2 {
+                return;
+            }
+            let last = line.glyphs.len() - 1;
+            let word_start = match self.text.wrap {
+                DebugTextWrap::Word => line
+                    .glyphs
+                    .iter()
+                    .rposition(PlacedGlyph::is_whitespace)
+                    .map(|index| index + 1)
+                    .filter(|&start| {
+                        line.glyphs[..start]
+                            .iter()
+                            .any(|glyph| !glyph.is_whitespace())
+                    }),
+                _ => None,
+            };
+            let moved = line.glyphs.split_off(word_start.unwrap_or(last));
+            line.trim_end();
+            let mut next = Line::default();
+            for glyph in moved {
+                next.place(glyph.char, glyph.glyph, &self.metrics);
+            }
+            self.lines.push(next);
+        }
+    }
+
+    /// Drops lines past the maximum, and the end of lines wider than the maximum.
+    fn truncate(&mut self) {
+        let mut last_kept = None;
+        if let Some(max_lines) = self.text.max_lines {
+            if self.lines.len() > max_lines {
+                self.lines.truncate(max_lines);
+                last_kept = max_lines.checked_sub(1);
+            }
+        }
+
+        let max_width = self.max_width();
+        let mut ellipsis = Line::default();
+        if self.text.overflow == DebugTextOverflow::Ellipsis {
+            match self.load_glyph('…') {
+                Some(glyph) => ellipsis.place('…', glyph, &self.metrics),
+                None => {
+                    for _ in 0..3 {
+                        if let Some(glyph) = self.load_glyph('.') {
+                            ellipsis.place('.', glyph, &self.metrics);
                         }
-                        glyph
-                    },
-                ));
+                    }
+                }
             }
         }
+        for (index, line) in self.lines.iter_mut().enumerate() {
+            if line.width(&self.metrics) <= max_width && last_kept != Some(index) {
+                continue;
+            }
+            // The ellipsis follows the letter spacing of the last kept glyph.
+            let available = max_width - ellipsis.end();
+            while !line.glyphs.is_empty() && line.width(&self.metrics) > available {
+                line.glyphs.pop();
+            }
+            line.trim_end();
+            for glyph in ellipsis.glyphs.iter() {
+                line.place(glyph.char, glyph.glyph.clone(), &self.metrics);
+            }
+        }
+    }
+}
 
-        let scale = self.scale * BASE_SCALE;
-        let mut line_widths = vec![];
-        let mut current_width = 0.;
-        let mut lines = 0;
-        for character in characters.iter() {
-            match character {
-                Character::Glyph(glyph) => {
-                    current_width += glyph.horizontal_advance as f32 * scale;
+fn load_glyph(font: &DebugTextFontData, face: &Face, detail: i8, char: char) -> Option<Glyph> {
+    let glyph_id = face.glyph_index(char)?;
+    if let Some(glyph) = {
+        let glyph_cache = font.glyphs.read().expect("failed to lock mesh cache");
+        glyph_cache.get(&(glyph_id, detail)).cloned()
+    } {
+        return Some(glyph);
+    }
+    let mut triangulator_builder = TriangulatorBuilder {
+        tolerance: 2_f32.powi(detail as i32),
+        ..Default::default()
+    };
+    face.outline_glyph(glyph_id, &mut triangulator_builder);
+    let triangles = if triangulator_builder.has_contours {
+        if let Ok(triangles) = triangulator_builder.triangulator.triangulate() {
+            triangles
+        } else {
+            vec![]
+        }
+    } else {
+        vec![]
+    };
+    let glyph = Glyph {
+        id: glyph_id,
+        triangles,
+        horizontal_advance: face.glyph_hor_advance(glyph_id).unwrap_or(0),
+    };
+    {
+        let mut glyph_cache = font.glyphs.write().expect("failed to lock mesh cache");
+        glyph_cache.insert((glyph_id, detail), glyph.clone());
+    }
+    Some(glyph)
+}
+
+/// The horizontal adjustment between two glyphs, in font units.
+fn kerning(font: &DebugTextFontData, face: &Face, left: GlyphId, right: GlyphId) -> i16 {
+    if let Some(kerning) = {
+        let kerning_cache = font.kerning.read().expect("failed to lock kerning cache");
+        kerning_cache.get(&(left, right)).copied()
+    } {
+        return kerning;
+    }
+    // Fonts with a GPOS kern feature may keep a legacy kern table for older software only.
+    let kerning = gpos_kerning(face, left, right)
+        .or_else(|| {
+            let kern = face.tables().kern?;
+            Some(
+                kern.subtables
+                    .into_iter()
+                    .filter(|subtable| {
+                        subtable.horizontal && !subtable.variable && !subtable.has_cross_stream
+                    })
+                    .filter_map(|subtable| subtable.glyphs_kerning(left, right))
+                    .fold(0, i16::saturating_add),
+            )
+        })
+        .unwrap_or(0);
+    {
+        let mut kerning_cache = font.kerning.write().expect("failed to lock kerning cache");
+        kerning_cache.insert((left, right), kerning);
+    }
+    kerning
+}
+
+/// Applies the pair adjustments of the kern feature of the default or latin script. Returns
+/// `None` when the font has no such feature.
+fn gpos_kerning(face: &Face, left: GlyphId, right: GlyphId) -> Option<i16> {
+    let gpos = face.tables().gpos?;
+    let script = gpos
+        .scripts
+        .find(Tag::from_bytes(b"DFLT"))
+        .or_else(|| gpos.scripts.find(Tag::from_bytes(b"latn")))
+        .or_else(|| gpos.scripts.get(0))?;
+    let language = script.default_language?;
+    let feature = language
+        .feature_indices
+        .into_iter()
+        .filter_map(|index| gpos.features.get(index))
+        .find(|feature| feature.tag == Tag::from_bytes(b"kern"))?;
+
+    let mut kerning = 0_i16;
+    for lookup in feature
+        .lookup_indices
+        .into_iter()
+        .filter_map(|index| gpos.lookups.get(index))
+    {
+        // Only the first subtable covering the pair applies.
+        let adjustment = lookup
+            .subtables
+            .into_iter::<PositioningSubtable>()
+            .find_map(|subtable| match subtable {
+                PositioningSubtable::Pair(PairAdjustment::Format1 { coverage, sets }) => {
+                    let (value, _) = sets.get(coverage.get(left)?)?.get(right)?;
+                    Some(value.x_advance)
                 }
-                Character::Newline => {
-                    line_widths.push(current_width);
-                    current_width = 0.;
-                    lines += 1;
+                PositioningSubtable::Pair(PairAdjustment::Format2 {
+                    coverage,
+                    classes,
+                    matrix,
+                }) => {
+                    if !coverage.contains(left) {
+                        return None;
+                    }
+                    let (value, _) = matrix.get((classes.0.get(left), classes.1.get(right)))?;
+                    Some(value.x_advance)
                 }
-            }
+                _ => None,
+            });
+        if let Some(adjustment) = adjustment {
+            kerning = kerning.saturating_add(adjustment);
         }
-        line_widths.push(current_width);
+    }
+    Some(kerning)
+}
+
+impl DebugDrawDrawable for DebugText {
+    fn to_mesh(&self) -> DebugDrawMesh {
+        let layout = Layout::new(self);
+        let scale = layout.metrics.scale;
+        let face = &layout.metrics.face;
+        let line_height = (face.height() + face.line_gap()) as f32 * scale * self.line_spacing;
+        let lines = layout.lines.len().saturating_sub(1) as f32;
+        let top = self.position.y
+            + match self.vertical_alignment {
+                DebugTextVerticalAlignment::Top => 0.,
+                DebugTextVerticalAlignment::Center => lines * line_height * 0.5,
+                DebugTextVerticalAlignment::Bottom => lines * line_height,
+            };
+        let line_starts = layout
+            .lines
+            .iter()
+            .map(|line| {
+                self.position.x
+                    - match self.alignment {
+                        DebugTextAlignment::Left => 0.,
+                        DebugTextAlignment::Center => layout.aligned_width(line) * 0.5,
+                        DebugTextAlignment::Right => layout.aligned_width(line),
+                    }
+            })
+            .collect::<Vec<_>>();
 
         let mut vertices = vec![];
         let mut indices = vec![];
-        let mut line = 0;
-        let mut position = self.position
-            + Vec2::new(
-                match self.alignment {
-                    DebugTextAlignment::Left => 0.,
-                    DebugTextAlignment::Center => line_widths[line] * -0.5,
-                    DebugTextAlignment::Right => line_widths[line] * -1.,
-                },
-                match self.vertical_alignment {
-                    DebugTextVerticalAlignment::Top => 0.,
-                    DebugTextVerticalAlignment::Center => {
-                        (lines as f32 * LINE_HEIGHT * scale) * 0.5
-                    }
-                    DebugTextVerticalAlignment::Bottom => lines as f32 * LINE_HEIGHT * scale,
+        if let Some(background) = self.background {
+            let (left, right) = layout.lines.iter().zip(line_starts.iter()).fold(
+                (self.position.x, self.position.x),
+                |(left, right), (line, start)| {
+                    (
+                        left.min(*start),
+                        right.max(start + layout.aligned_width(line)),
+                    )
                 },
             );
-        for character in characters.iter() {
-            match character {
-                Character::Glyph(glyph) => {
-                    vertices.reserve(glyph.triangles.len() * 3);
-                    indices.reserve(glyph.triangles.len() * 3);
-                    for triangle in glyph.triangles.iter() {
-                        indices.push(vertices.len() as u32);
-                        vertices.push(DebugDrawVertex {
-                            position: position + Vec2::from(triangle[0]) * scale,
-                            color: self.color,
-                        });
-                        indices.push(vertices.len() as u32);
-                        vertices.push(DebugDrawVertex {
-                            position: position + Vec2::from(triangle[1]) * scale,
-                            color: self.color,
-                        });
+            let min = Vec2::new(
+                left - self.padding,
+                top - lines * line_height + face.descender() as f32 * scale - self.padding,
+            );
+            let max = Vec2::new(
+                right + self.padding,
+                top + face.ascender() as f32 * scale + self.padding,
+            );
+            for position in [min, Vec2::new(max.x, min.y), max, Vec2::new(min.x, max.y)] {
+                vertices.push(DebugDrawVertex {
+                    position,
+                    color: background,
+                    ..Default::default()
+                });
+            }
+            indices.extend([0, 1, 2, 0, 2, 3]);
+        }
+
+        for (index, (line, start)) in layout.lines.iter().zip(line_starts).enumerate() {
+            let origin = Vec2::new(start, top - index as f32 * line_height);
+            for placed in line.glyphs.iter() {
+                let position = origin + Vec2::new(placed.x, 0.);
+                let glyph = &placed.glyph;
+                vertices.reserve(glyph.triangles.len() * 3);
+                indices.reserve(glyph.triangles.len() * 3);
+                for triangle in glyph.triangles.iter() {
+                    for point in triangle {
                         indices.push(vertices.len() as u32);
                         vertices.push(DebugDrawVertex {
-                            position: position + Vec2::from(triangle[2]) * scale,
+                            position: position + Vec2::from(*point) * scale,
                             color: self.color,
+                            ..Default::default()
                         });
                     }
-                    position.x += glyph.horizontal_advance as f32 * scale;
-                }
-                Character::Newline => {
-                    line += 1;
-                    //position.x = self.position.x + line_widths[line] * -0.5;
-                    position.x = match self.alignment {
-                        DebugTextAlignment::Left => self.position.x,
-                        DebugTextAlignment::Center => self.position.x - line_widths[line] * 0.5,
-                        DebugTextAlignment::Right => self.position.x - line_widths[line],
-                    };
-                    position.y -= LINE_HEIGHT * scale;
                 }
             }
         }
@@ -183,17 +553,28 @@ impl DebugDrawDrawable for DebugText {
             vertices,
             indices,
             depth: self.depth,
+            ..Default::default()
         }
     }
 }
 
 #[derive(Default)]
 struct TriangulatorBuilder {
+    /// The maximum distance between curves and their segments, in font units.
+    tolerance: f32,
     contour: Vec<[f32; 2]>,
     triangulator: Triangulator,
     has_contours: bool,
 }
 
+impl TriangulatorBuilder {
+    /// The segments needed for a curve whose distance to its chord over a step h is at most
+    /// `error * h²`.
+    fn segments(&self, error: f32) -> u32 {
+        ((error / self.tolerance).sqrt().ceil() as u32).clamp(1, MAX_CURVE_SEGMENTS)
+    }
+}
+
 impl ttf_parser::OutlineBuilder for TriangulatorBuilder {
     fn move_to(&mut self, x: f32, y: f32) {
         if self.contour.len() > 0 {
@@ -209,26 +590,47 @@ impl ttf_parser::OutlineBuilder for TriangulatorBuilder {
     }
 
     fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) {
-        if let Some(last) = self.contour.last() {
-            let mut p0 = Vec2::from(*last);
-            let mut p1 = Vec2::new(x1, y1);
-            let p2 = Vec2::new(x, y);
-            let d01 = (p1 - p0) * INVERSE_RESOLUTION;
-            let d12 = (p2 - p1) * INVERSE_RESOLUTION;
-            for i in 0..(RESOLUTION - 1) {
-                p0 += d01;
-                p1 += d12;
-                let cp = p0 + (p1 - p0) * (i as f32) * INVERSE_RESOLUTION;
-                self.contour.push([cp.x, cp.y]);
-            }
-            self.contour.push([x, y]);
-        } else {
+        let Some(&last) = self.contour.last() else {
             self.contour.push([x, y]);
+            return;
+        };
+        let p0 = Vec2::from(last);
+        let p1 = Vec2::new(x1, y1);
+        let p2 = Vec2::new(x, y);
+        // The distance between a quadratic and its chord over a step h is at most
+        // |p0 - 2p1 + p2| * h² / 4.
+        let segments = self.segments((p0 - 2. * p1 + p2).length() / 4.);
+        for i in 1..segments {
+            let t = i as f32 / segments as f32;
+            let u = 1. - t;
+            let point = p0 * (u * u) + p1 * (2. * u * t) + p2 * (t * t);
+            self.contour.push([point.x, point.y]);
         }
+        self.contour.push([x, y]);
     }
 
-    fn curve_to(&mut self, _x1: f32, _y1: f32, _x2: f32, _y2: f32, x: f32, y: f32) {
-        // TODO
+    fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
+        let Some(&last) = self.contour.last() else {
+            self.contour.push([x, y]);
+            return;
+        };
+        let p0 = Vec2::from(last);
+        let p1 = Vec2::new(x1, y1);
+        let p2 = Vec2::new(x2, y2);
+        let p3 = Vec2::new(x, y);
+        // The second derivative of a cubic is at most 6 * max(|p0 - 2p1 + p2|, |p1 - 2p2 + p3|),
+        // and a chord over a step h is within an eighth of it times h².
+        let curvature = (p0 - 2. * p1 + p2)
+            .length()
+            .max((p1 - 2. * p2 + p3).length());
+        let segments = self.segments(curvature * 0.75);
+        for i in 1..segments {
+            let t = i as f32 / segments as f32;
+            let u = 1. - t;
+            let point =
+                p0 * (u * u * u) + p1 * (3. * u * u * t) + p2 * (3. * u * t * t) + p3 * (t * t * t);
+            self.contour.push([point.x, point.y]);
+        }
         self.contour.push([x, y]);
     }
 
@@ -240,3 +642,153 @@ impl ttf_parser::OutlineBuilder for TriangulatorBuilder {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn text(text: &str) -> DebugText {
+        DebugText {
+            text: text.to_owned(),
+            kerning: false,
+            ..Default::default()
+        }
+    }
+
+    /// The characters of every line.
+    fn lines(text: &DebugText) -> Vec<String> {
+        Layout::new(text)
+            .lines
+            .iter()
+            .map(|line| line.glyphs.iter().map(|glyph| glyph.char).collect())
+            .collect()
+    }
+
+    /// The x position of every glyph of a line.
+    fn positions(text: &DebugText, line: usize) -> Vec<f32> {
+        Layout::new(text).lines[line]
+            .glyphs
+            .iter()
+            .map(|glyph| glyph.x)
+            .collect()
+    }
+
+    /// The advance of a character at a scale of one, without kerning or spacing.
+    fn advance(char: char) -> f32 {
+        let text = text("");
+        let layout = Layout::new(&text);
+        let glyph = layout.load_glyph(char).unwrap();
+        glyph.horizontal_advance as f32 * layout.metrics.scale
+    }
+
+    #[test]
+    fn breaks_words_wider_than_the_line() {
+        let text = DebugText {
+            max_width: Some(advance('a') * 2.5),
+            ..text("a aaaaa")
+        };
+        assert_eq!(lines(&text), ["a", "aa", "aa", "a"]);
+        assert_eq!(positions(&text, 1), [0., advance('a')]);
+    }
+
+    #[test]
+    fn keeps_leading_whitespace() {
+        let text = DebugText {
+            max_width: Some(advance(' ') * 2. + advance('a') * 2.5),
+            ..text("  aaa")
+        };
+        assert_eq!(lines(&text), ["  aa", "a"]);
+        assert_eq!(
+            positions(&text, 0),
+            [
+                0.,
+                advance(' '),
+                advance(' ') * 2.,
+                advance(' ') * 2. + advance('a')
+            ]
+        );
+    }
+
+    #[test]
+    fn wraps_at_any_character() {
+        let words = DebugText {
+            max_width: Some(advance('a') * 3. + advance(' ') + 0.01),
+            ..text("aa aa")
+        };
+        assert_eq!(lines(&words), ["aa", "aa"]);
+        let characters = DebugText {
+            wrap: DebugTextWrap::Character,
+            ..words
+        };
+        assert_eq!(lines(&characters), ["aa a", "a"]);
+    }
+
+    #[test]
+    fn drops_every_line_without_lines() {
+        let text = DebugText {
+            max_lines: Some(0),
+            overflow: DebugTextOverflow::Ellipsis,
+            ..text("a\na")
+        };
+        assert!(lines(&text).is_empty());
+    }
+
+    #[test]
+    fn keeps_an_ellipsis_wider_than_the_line() {
+        let text = DebugText {
+            max_width: Some(advance('…') / 2.),
+            wrap: DebugTextWrap::None,
+            overflow: DebugTextOverflow::Ellipsis,
+            ..text("aaa")
+        };
+        assert_eq!(lines(&text), ["…"]);
+        assert_eq!(positions(&text, 0), [0.]);
+    }
+
+    #[test]
+    fn moves_tabs_to_the_next_stop() {
+        let tab = advance(' ') * 4.;
+        let text = text("a\tb\n\tb");
+        assert_eq!(positions(&text, 0), [0., advance('a'), tab]);
+        assert_eq!(positions(&text, 1), [0., tab]);
+    }
+
+    #[test]
+    fn kerns_pairs_from_the_bundled_font() {
+        let (font, face) = DebugTextFont::DEFAULT.resolve();
+        let a = face.glyph_index('A').unwrap();
+        let v = face.glyph_index('V').unwrap();
+        assert!(kerning(font, &face, a, v) < 0);
+    }
+
+    #[test]
+    fn moves_kerned_glyphs() {
+        let kerned = DebugText {
+            kerning: true,
+            ..text("AV")
+        };
+        let unkerned = positions(&text("AV"), 0);
+        assert_eq!(unkerned, [0., advance('A')]);
+        let kerned = positions(&kerned, 0);
+        assert_eq!(kerned[0], 0.);
+        assert!(kerned[1] < unkerned[1]);
+    }
+
+    #[test]
+    fn aligns_by_trailing_whitespace_without_a_maximum_width() {
+        let spaced = DebugText {
+            letter_spacing: 0.1,
+            ..text("a ")
+        };
+        let layout = Layout::new(&spaced);
+        let width = advance('a') + advance(' ') + layout.metrics.letter_spacing;
+        assert!((layout.aligned_width(&layout.lines[0]) - width).abs() < 1e-4);
+
+        let wrapped = DebugText {
+            max_width: Some(100.),
+            ..spaced.clone()
+        };
+        let layout = Layout::new(&wrapped);
+        assert!((layout.aligned_width(&layout.lines[0]) - advance('a')).abs() < 1e-4);
+    }
+}
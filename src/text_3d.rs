@@ -0,0 +1,63 @@
+use bevy::prelude::*;
+
+use crate::{
+    DebugDrawDrawable, DebugDrawDrawable3d, DebugDrawMesh3d, DebugDrawVertex3d, DebugText,
+    DebugTextAlignment, DebugTextFont, DebugTextVerticalAlignment,
+};
+
+/// Text in 3D space that always faces the camera.
+#[derive(Clone, Debug)]
+pub struct DebugText3d {
+    pub text: String,
+    pub position: Vec3,
+    pub scale: f32,
+    pub color: Color,
+    pub alignment: DebugTextAlignment,
+    pub vertical_alignment: DebugTextVerticalAlignment,
+    pub font: DebugTextFont,
+    /// The maximum distance between the curves of glyphs and their flattened outlines.
+    pub tolerance: f32,
+}
+
+impl Default for DebugText3d {
+    fn default() -> Self {
+        Self {
+            text: "".to_owned(),
+            position: Vec3::ZERO,
+            scale: 0.02,
+            color: Color::BLACK,
+            alignment: DebugTextAlignment::Left,
+            vertical_alignment: DebugTextVerticalAlignment::Top,
+            font: DebugTextFont::DEFAULT,
+            tolerance: 0.002,
+        }
+    }
+}
+
+impl DebugDrawDrawable3d for DebugText3d {
+    fn to_mesh_3d(&self) -> DebugDrawMesh3d {
+        let mesh = DebugText {
+            text: self.text.clone(),
+            scale: self.scale,
+            color: self.color,
+            alignment: self.alignment,
+            vertical_alignment: self.vertical_alignment,
+            font: self.font.clone(),
+            tolerance: self.tolerance,
+            ..Default::default()
+        }
+        .to_mesh();
+        DebugDrawMesh3d {
+            vertices: mesh
+                .vertices
+                .iter()
+                .map(|vertex| DebugDrawVertex3d {
+                    position: vertex.position.extend(0.),
+                    color: vertex.color,
+                })
+                .collect(),
+            indices: mesh.indices,
+            billboard: Some(self.position),
+        }
+    }
+}
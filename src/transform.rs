@@ -0,0 +1,116 @@
+use std::ops::{Deref, DerefMut};
+
+use bevy::{math::Affine2, prelude::*};
+
+use crate::{DebugDraw, DebugDrawMesh};
+
+impl DebugDraw {
+    /// Pushes a transform applied to every following draw, on top of the current one, until it is
+    /// popped.
+    pub fn push_transform(&mut self, transform: impl Into<DebugDrawTransform>) {
+        let transform = self.current_transform() * transform.into().0;
+        self.transform_stack.push(transform);
+    }
+
+    pub fn pop_transform(&mut self) {
+        self.transform_stack.pop();
+    }
+
+    /// Pushes the transform of an entity. Returns `false` if it doesn't have a [`GlobalTransform`].
+    pub fn push_entity_transform(
+        &mut self,
+        entity: Entity,
+        transform_query: &Query<&GlobalTransform>,
+    ) -> bool {
+        match transform_query.get(entity) {
+            Ok(transform) => {
+                self.push_transform(transform);
+                true
+            }
+            Err(_) => false,
+        }
+    }
+
+    /// Pushes a transform that is popped when the returned guard is dropped.
+    pub fn scoped_transform(
+        &mut self,
+        transform: impl Into<DebugDrawTransform>,
+    ) -> DebugDrawTransformGuard<'_> {
+        self.push_transform(transform);
+        DebugDrawTransformGuard { debug_draw: self }
+    }
+
+    /// The combination of every transform on the stack.
+    pub fn current_transform(&self) -> Affine2 {
+        self.transform_stack
+            .last()
+            .copied()
+            .unwrap_or(Affine2::IDENTITY)
+    }
+}
+
+/// A 2D transform for [`DebugDraw::push_transform`]. 3D transforms are projected on the XY plane.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DebugDrawTransform(pub Affine2);
+
+impl From<Affine2> for DebugDrawTransform {
+    fn from(affine: Affine2) -> Self {
+        Self(affine)
+    }
+}
+
+impl From<Transform> for DebugDrawTransform {
+    fn from(transform: Transform) -> Self {
+        let (rotation, _, _) = transform.rotation.to_euler(EulerRot::ZYX);
+        Self(Affine2::from_scale_angle_translation(
+            transform.scale.truncate(),
+            rotation,
+            transform.translation.truncate(),
+        ))
+    }
+}
+
+impl From<&GlobalTransform> for DebugDrawTransform {
+    fn from(transform: &GlobalTransform) -> Self {
+        transform.compute_transform().into()
+    }
+}
+
+impl From<GlobalTransform> for DebugDrawTransform {
+    fn from(transform: GlobalTransform) -> Self {
+        (&transform).into()
+    }
+}
+
+/// Pops its transform from the [`DebugDraw`] when dropped, and derefs to it in the meantime.
+pub struct DebugDrawTransformGuard<'a> {
+    debug_draw: &'a mut DebugDraw,
+}
+
+impl Deref for DebugDrawTransformGuard<'_> {
+    type Target = DebugDraw;
+
+    fn deref(&self) -> &Self::Target {
+        self.debug_draw
+    }
+}
+
+impl DerefMut for DebugDrawTransformGuard<'_> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.debug_draw
+    }
+}
+
+impl Drop for DebugDrawTransformGuard<'_> {
+    fn drop(&mut self) {
+        self.debug_draw.pop_transform();
+    }
+}
+
+impl DebugDrawMesh {
+    pub fn transform(&mut self, transform: Affine2) {
+        for vertex in self.vertices.iter_mut() {
+            vertex.position = transform.transform_point2(vertex.position);
+        }
+    }
+}
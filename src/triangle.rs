@@ -29,14 +29,17 @@ impl DebugDrawDrawable for DebugTriangle {
             DebugDrawVertex {
                 position: a,
                 color: self.color,
+                ..Default::default()
             },
             DebugDrawVertex {
                 position: b,
                 color: self.color,
+                ..Default::default()
             },
             DebugDrawVertex {
                 position: c,
                 color: self.color,
+                ..Default::default()
             },
         ];
         let indices = if clockwise {
@@ -48,6 +51,7 @@ impl DebugDrawDrawable for DebugTriangle {
             vertices,
             indices,
             depth: self.depth,
+            ..Default::default()
         }
     }
 }